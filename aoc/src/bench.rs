@@ -0,0 +1,152 @@
+//! Repeated, per-phase timing of solutions.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use serde::Serialize;
+
+use crate::{Error, Solution};
+
+/// How long one run spent parsing and answering each part.
+#[derive(Debug, Clone, Copy)]
+pub struct Timings {
+    pub parse: Duration,
+    pub part1: Duration,
+    pub part2: Duration,
+}
+
+/// Times one run of a particular day over an input; what [`bench()`] calls repeatedly.
+pub type Timer = fn(&str) -> Result<Timings, Error>;
+
+/// Times one full run of `S` over `input`.
+pub fn time<S: Solution>(input: &str) -> Result<Timings, Error> {
+    let start = Instant::now();
+    let parsed = black_box(S::parse(input)?);
+    let parse = start.elapsed();
+
+    let start = Instant::now();
+    black_box(S::part1(&parsed)?);
+    let part1 = start.elapsed();
+
+    let start = Instant::now();
+    black_box(S::part2(&parsed)?);
+    let part2 = start.elapsed();
+
+    Ok(Timings {
+        parse,
+        part1,
+        part2,
+    })
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Stats {
+    #[serde(rename = "min_ns", serialize_with = "nanos")]
+    pub min: Duration,
+    #[serde(rename = "median_ns", serialize_with = "nanos")]
+    pub median: Duration,
+    #[serde(rename = "max_ns", serialize_with = "nanos")]
+    pub max: Duration,
+}
+
+pub(crate) fn nanos<S: serde::Serializer>(
+    duration: &Duration,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u64(duration.as_nanos().try_into().unwrap_or(u64::MAX))
+}
+
+impl Stats {
+    /// Summarizes `samples`, which must not be empty. The median of an even number of samples is
+    /// the mean of the two middle ones.
+    pub fn of(samples: &[Duration]) -> Self {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let mid = sorted.len() / 2;
+        let median = if sorted.len().is_multiple_of(2) {
+            (sorted[mid - 1] + sorted[mid]) / 2
+        } else {
+            sorted[mid]
+        };
+
+        Self {
+            min: sorted[0],
+            median,
+            max: sorted[sorted.len() - 1],
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Report {
+    pub day: u8,
+    pub runs: usize,
+    pub parse: Stats,
+    pub part1: Stats,
+    pub part2: Stats,
+}
+
+/// Runs `timer` over `input` `runs` times and summarizes each phase.
+pub fn bench(day: u8, timer: Timer, input: &str, runs: usize) -> Result<Report, Error> {
+    let runs = runs.max(1);
+    let timings = (0..runs)
+        .map(|_| timer(input))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let phase = |f: fn(&Timings) -> Duration| Stats::of(&timings.iter().map(f).collect::<Vec<_>>());
+
+    Ok(Report {
+        day,
+        runs,
+        parse: phase(|t| t.parse),
+        part1: phase(|t| t.part1),
+        part2: phase(|t| t.part2),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(ms: u64) -> Duration {
+        Duration::from_millis(ms)
+    }
+
+    #[test]
+    fn stats_of_odd_samples() {
+        let stats = Stats::of(&[ms(5), ms(1), ms(3)]);
+        assert_eq!(
+            stats,
+            Stats {
+                min: ms(1),
+                median: ms(3),
+                max: ms(5)
+            }
+        );
+    }
+
+    #[test]
+    fn stats_of_even_samples() {
+        let stats = Stats::of(&[ms(4), ms(1), ms(2), ms(8)]);
+        assert_eq!(stats.median, ms(3));
+    }
+
+    #[test]
+    fn bench_report_serializes_nanoseconds() {
+        let timer: Timer = |_| {
+            Ok(Timings {
+                parse: Duration::from_nanos(10),
+                part1: Duration::from_nanos(20),
+                part2: Duration::from_nanos(30),
+            })
+        };
+
+        let report = bench(1, timer, "", 3).unwrap();
+        let json = serde_json::to_value(&report).unwrap();
+
+        assert_eq!(json["runs"], 3);
+        assert_eq!(json["part1"]["median_ns"], 20);
+        assert_eq!(json["part2"]["max_ns"], 30);
+    }
+}
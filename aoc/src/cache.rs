@@ -0,0 +1,129 @@
+//! Local store of downloaded puzzle inputs, laid out as `<dir>/<year>/day<day>/<account>.txt`.
+
+use std::path::{Path, PathBuf};
+
+use crate::Error;
+
+/// The event these solutions are for.
+pub const YEAR: u16 = 2022;
+
+/// Which cached input to use, shared by everything that reads or writes the cache.
+#[derive(Debug, Clone, clap::Args)]
+pub struct CacheArgs {
+    /// Input cache directory [default: $XDG_CACHE_HOME/aoc or ~/.cache/aoc]
+    #[arg(long, env = "AOC_CACHE_DIR")]
+    pub cache_dir: Option<PathBuf>,
+
+    /// Name of the account whose input to use, for keeping several accounts' inputs apart
+    #[arg(long, env = "AOC_ACCOUNT", default_value = "default")]
+    pub account: String,
+}
+
+impl CacheArgs {
+    pub fn cache(&self) -> Result<Cache, Error> {
+        match &self.cache_dir {
+            Some(dir) => Ok(Cache::new(dir)),
+            None => Cache::default_dir()
+                .map(Cache::new)
+                .ok_or_else(|| "no cache directory, set --cache-dir or AOC_CACHE_DIR".into()),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Cache {
+    dir: PathBuf,
+}
+
+impl Cache {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// `$XDG_CACHE_HOME/aoc`, falling back to `$HOME/.cache/aoc`.
+    pub fn default_dir() -> Option<PathBuf> {
+        let non_empty = |var| std::env::var_os(var).filter(|v| !v.is_empty());
+
+        non_empty("XDG_CACHE_HOME")
+            .map(PathBuf::from)
+            .or_else(|| non_empty("HOME").map(|home| Path::new(&home).join(".cache")))
+            .map(|dir| dir.join("aoc"))
+    }
+
+    pub fn path(&self, year: u16, day: u8, account: &str) -> Result<PathBuf, Error> {
+        let valid = !account.is_empty()
+            && !account.starts_with('.')
+            && account
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || "-_.".contains(c));
+
+        if !valid {
+            return Err(format!("invalid account name {account:?}").into());
+        }
+
+        Ok(self
+            .dir
+            .join(year.to_string())
+            .join(format!("day{day}"))
+            .join(format!("{account}.txt")))
+    }
+
+    /// The cached input, or `None` if it was never stored.
+    pub fn read(&self, year: u16, day: u8, account: &str) -> Result<Option<String>, Error> {
+        let path = self.path(year, day, account)?;
+
+        match std::fs::read_to_string(&path) {
+            Ok(input) => Ok(Some(input)),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(format!("failed to read {}: {err}", path.display()).into()),
+        }
+    }
+
+    pub fn write(&self, year: u16, day: u8, account: &str, input: &str) -> Result<PathBuf, Error> {
+        let path = self.path(year, day, account)?;
+
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        std::fs::write(&path, input)
+            .map_err(|err| format!("failed to write {}: {err}", path.display()))?;
+
+        Ok(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn path_is_keyed_by_year_day_and_account() {
+        let cache = Cache::new("/cache");
+        assert_eq!(
+            cache.path(2022, 7, "work").unwrap(),
+            Path::new("/cache/2022/day7/work.txt")
+        );
+    }
+
+    #[test]
+    fn account_cannot_escape_the_cache() {
+        let cache = Cache::new("/cache");
+        assert!(cache.path(2022, 7, "../other").is_err());
+        assert!(cache.path(2022, 7, "a/b").is_err());
+        assert!(cache.path(2022, 7, "").is_err());
+    }
+
+    #[test]
+    fn write_then_read() {
+        let dir = std::env::temp_dir().join(format!("aoc-cache-{}", std::process::id()));
+        let cache = Cache::new(&dir);
+
+        assert_eq!(cache.read(2022, 1, "default").unwrap(), None);
+        cache.write(2022, 1, "default", "1000\n").unwrap();
+        let input = cache.read(2022, 1, "default").unwrap();
+
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(input.as_deref(), Some("1000\n"));
+    }
+}
@@ -0,0 +1,158 @@
+use std::fmt::{self, Display, Formatter};
+use std::io;
+
+/// Anything that can go wrong reading, parsing or solving a puzzle.
+#[derive(Debug)]
+pub enum Error {
+    /// Malformed input, with 1-based line and column.
+    Parse {
+        line: usize,
+        column: usize,
+        message: String,
+    },
+    Io(io::Error),
+    /// Well-formed input the puzzle has no answer for, and any other failure.
+    Message(String),
+}
+
+impl Error {
+    pub fn parse(line: usize, column: usize, message: impl Into<String>) -> Self {
+        Self::Parse {
+            line,
+            column,
+            message: message.into(),
+        }
+    }
+
+    /// A parse error at the start of `token`, which must be a slice of `input`.
+    pub fn at(input: &str, token: &str, message: impl Into<String>) -> Self {
+        let before = &input[..offset_of(input, token)];
+        let line = before.matches('\n').count() + 1;
+        let line_start = before.rfind('\n').map_or(0, |idx| idx + 1);
+        let column = before[line_start..].chars().count() + 1;
+
+        Self::parse(line, column, message)
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Parse {
+                line,
+                column,
+                message,
+            } => write!(f, "line {line}, column {column}: {message}"),
+            Self::Io(err) => write!(f, "{err}"),
+            Self::Message(message) => write!(f, "{message}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(err: io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+impl From<String> for Error {
+    fn from(message: String) -> Self {
+        Self::Message(message)
+    }
+}
+
+impl From<&str> for Error {
+    fn from(message: &str) -> Self {
+        Self::Message(message.to_owned())
+    }
+}
+
+/// A malformed line; [`parse_lines`] turns it into an [`Error::Parse`] with the line number.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub column: usize,
+    pub message: String,
+}
+
+impl ParseError {
+    /// An error about the line as a whole, reported at its first column.
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            column: 1,
+            message: message.into(),
+        }
+    }
+
+    /// An error at the start of `token`, which must be a slice of `line`.
+    pub fn at(line: &str, token: &str, message: impl Into<String>) -> Self {
+        let column = line[..offset_of(line, token)].chars().count() + 1;
+        Self::new(message).at_column(column)
+    }
+
+    pub fn at_column(self, column: usize) -> Self {
+        Self { column, ..self }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+fn offset_of(outer: &str, inner: &str) -> usize {
+    let offset = (inner.as_ptr() as usize).wrapping_sub(outer.as_ptr() as usize);
+    assert!(
+        offset <= outer.len() && offset + inner.len() <= outer.len(),
+        "not a slice of the parsed input"
+    );
+    offset
+}
+
+/// Parses each line of `input` with `parse`, stopping at the first malformed one.
+pub fn parse_lines<T>(
+    input: &str,
+    mut parse: impl FnMut(&str) -> Result<T, ParseError>,
+) -> Result<Vec<T>, Error> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(idx, line)| {
+            parse(line).map_err(|err| Error::parse(idx + 1, err.column, err.message))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn error_at_offset() {
+        let input = "ab\ncdef\n";
+        let err = Error::at(input, &input[5..], "bad");
+        assert_eq!(err.to_string(), "line 2, column 3: bad");
+    }
+
+    #[test]
+    fn parse_lines_reports_line_and_column() {
+        let err = parse_lines("1\n2\n3x\n", |line| {
+            line.parse::<u32>()
+                .map_err(|_| ParseError::at(line, &line[1..], "not a number"))
+        })
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "line 3, column 2: not a number");
+    }
+}
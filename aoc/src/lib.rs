@@ -0,0 +1,371 @@
+use std::fmt::{self, Display, Formatter};
+use std::io::{self, BufRead, BufReader, Read};
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+use cache::{CacheArgs, YEAR};
+use clap::error::ErrorKind;
+use clap::parser::ValueSource;
+use clap::{CommandFactory, FromArgMatches, Parser};
+use output::Format;
+use serde::Serialize;
+
+pub mod bench;
+pub mod cache;
+mod error;
+pub mod output;
+pub mod verify;
+
+pub use error::{parse_lines, Error, ParseError};
+
+/// [`solve`] for a specific day, so days can be picked at runtime.
+pub type Solver = fn(&str, &[Part]) -> Result<Vec<Answer>, Error>;
+
+/// A puzzle solution: parses the raw input once, then answers each part from the parsed form.
+pub trait Solution {
+    const DAY: u8;
+
+    type Input;
+    type Part1: Display;
+    type Part2: Display;
+
+    fn parse(input: &str) -> Result<Self::Input, Error>;
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1, Error>;
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, Error>;
+}
+
+/// Type-erased entry points of one day's [`Solution`], so days can be picked at runtime.
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub number: u8,
+    pub solve: Solver,
+    pub time: bench::Timer,
+}
+
+impl Day {
+    pub const fn of<S: Solution>() -> Self {
+        Self {
+            number: S::DAY,
+            solve: solve::<S>,
+            time: bench::time::<S>,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+impl Serialize for Part {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Part::One => serializer.serialize_u8(1),
+            Part::Two => serializer.serialize_u8(2),
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(format!("expected part 1 or 2, got {s:?}")),
+        }
+    }
+}
+
+/// Where to read the puzzle input from, shared by the `aoc` binary and each day's own binary.
+#[derive(Debug, Clone, clap::Args)]
+pub struct InputArgs {
+    /// Puzzle input file, or `-` for stdin; the cached input is read when omitted
+    pub input: Option<PathBuf>,
+
+    #[command(flatten)]
+    pub cache: CacheArgs,
+}
+
+impl InputArgs {
+    pub fn read(&self, day: u8) -> Result<String, Error> {
+        let mut input = String::new();
+        self.reader(day)?.read_to_string(&mut input)?;
+        Ok(input)
+    }
+
+    /// Opens the input for reading it incrementally, for inputs too large to hold in memory.
+    pub fn reader(&self, day: u8) -> Result<Box<dyn BufRead>, Error> {
+        let path = match &self.input {
+            Some(path) if path.as_os_str() == "-" => return Ok(Box::new(io::stdin().lock())),
+            Some(path) => path.clone(),
+            None => {
+                let cache = self.cache.cache()?;
+                let account = &self.cache.account;
+                let path = cache.path(YEAR, day, account)?;
+
+                if !path.exists() {
+                    return Err(format!(
+                        "no cached input for day {day} ({account}), \
+                         run `aoc fetch {day}` or pass an input file"
+                    )
+                    .into());
+                }
+
+                path
+            }
+        };
+
+        let file = std::fs::File::open(&path)
+            .map_err(|err| format!("failed to read {}: {err}", path.display()))?;
+        Ok(Box::new(BufReader::new(file)))
+    }
+}
+
+/// Command-line arguments of a day's own binary, for days that add options of their own.
+#[derive(Debug, Clone, clap::Args)]
+pub struct DayArgs {
+    #[command(flatten)]
+    pub input: InputArgs,
+
+    /// How to print the answers
+    #[arg(long, value_enum, default_value_t)]
+    pub format: Format,
+}
+
+impl DayArgs {
+    /// Reads the input, then solves and prints `parts` of `S`.
+    pub fn solve<S: Solution>(&self, parts: &[Part]) -> Result<(), Error> {
+        let input = self.input.read(S::DAY)?;
+        let answers = solve::<S>(&input, parts)?;
+        output::print(self.format, S::DAY, &input, &answers);
+        Ok(())
+    }
+}
+
+/// The answer to one part, with how long parsing and solving it took.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Answer {
+    pub part: Part,
+    pub answer: String,
+    pub parse: Duration,
+    pub solve: Duration,
+}
+
+/// Parses `input` and computes the answer to each of `parts`, in order.
+pub fn solve<S: Solution>(input: &str, parts: &[Part]) -> Result<Vec<Answer>, Error> {
+    let start = Instant::now();
+    let parsed = S::parse(input)?;
+    answer::<S>(&parsed, start.elapsed(), parts)
+}
+
+/// Computes the answer to each of `parts` from input parsed by other means than
+/// [`Solution::parse`], which took `parse` to do.
+pub fn answer<S: Solution>(
+    parsed: &S::Input,
+    parse: Duration,
+    parts: &[Part],
+) -> Result<Vec<Answer>, Error> {
+    parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let answer = match part {
+                Part::One => S::part1(parsed)?.to_string(),
+                Part::Two => S::part2(parsed)?.to_string(),
+            };
+
+            Ok(Answer {
+                part,
+                answer,
+                parse,
+                solve: start.elapsed(),
+            })
+        })
+        .collect()
+}
+
+/// Reads the input named on the command line, then solves and prints `parts` of `S`; the entry
+/// point of each day's own binary.
+pub fn run<S: Solution>(parts: &[Part]) -> ExitCode {
+    #[derive(Parser)]
+    struct Cli {
+        #[command(flatten)]
+        args: DayArgs,
+    }
+
+    let matches = Cli::command()
+        .about(format!("Advent of Code {YEAR}, day {}", S::DAY))
+        .get_matches();
+    let cli = Cli::from_arg_matches(&matches).unwrap_or_else(|err| err.exit());
+
+    exit_code(cli.args.solve::<S>(parts))
+}
+
+/// Parses the command line of a day's own binary that solves the puzzle from its top-level
+/// arguments and does something else in its subcommands.
+///
+/// Unlike `args_conflicts_with_subcommands`, options marked `global` may come before the
+/// subcommand as well as after it; any other top-level argument still cannot be combined with a
+/// subcommand.
+pub fn parse_args<C: Parser>() -> C {
+    let mut command = C::command();
+    let matches = command.get_matches_mut();
+
+    if let Some((subcommand, _)) = matches.subcommand() {
+        let conflict = command.get_arguments().find(|arg| {
+            !arg.is_global_set()
+                && matches.value_source(arg.get_id().as_str()) == Some(ValueSource::CommandLine)
+        });
+
+        if let Some(arg) = conflict {
+            let message = format!("the argument '{arg}' cannot be used with '{subcommand}'");
+            command.error(ErrorKind::ArgumentConflict, message).exit();
+        }
+    }
+
+    C::from_arg_matches(&matches).unwrap_or_else(|err| err.exit())
+}
+
+/// Reports a failed command on stderr.
+pub fn exit_code(result: Result<(), Error>) -> ExitCode {
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        const DAY: u8 = 0;
+
+        type Input = Vec<u32>;
+        type Part1 = u32;
+        type Part2 = usize;
+
+        fn parse(input: &str) -> Result<Self::Input, Error> {
+            parse_lines(input, |line| {
+                line.parse().map_err(|_| ParseError::new("not a number"))
+            })
+        }
+
+        fn part1(input: &Self::Input) -> Result<Self::Part1, Error> {
+            Ok(input.iter().sum())
+        }
+
+        fn part2(input: &Self::Input) -> Result<Self::Part2, Error> {
+            Ok(input.len())
+        }
+    }
+
+    fn cache_args(cache_dir: Option<PathBuf>) -> CacheArgs {
+        CacheArgs {
+            cache_dir,
+            account: "default".to_string(),
+        }
+    }
+
+    #[test]
+    fn parse_part() {
+        assert_eq!("1".parse::<Part>(), Ok(Part::One));
+        assert_eq!("2".parse::<Part>(), Ok(Part::Two));
+        assert!("3".parse::<Part>().is_err());
+    }
+
+    #[test]
+    fn solve_selected_parts_in_order() {
+        let answers = solve::<Sum>("1\n2\n3\n", &[Part::Two, Part::One]).unwrap();
+        let answers = answers
+            .into_iter()
+            .map(|Answer { part, answer, .. }| (part, answer))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            answers,
+            vec![(Part::Two, "3".to_string()), (Part::One, "6".to_string())]
+        );
+    }
+
+    #[test]
+    fn read_input_from_file() {
+        let path = std::env::temp_dir().join(format!("aoc-input-{}.txt", std::process::id()));
+        std::fs::write(&path, "1\n2\n").unwrap();
+
+        let input = InputArgs {
+            input: Some(path.clone()),
+            cache: cache_args(None),
+        }
+        .read(1);
+
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(input.unwrap(), "1\n2\n");
+    }
+
+    #[test]
+    fn read_missing_input_file() {
+        let err = InputArgs {
+            input: Some("does/not/exist.txt".into()),
+            cache: cache_args(None),
+        }
+        .read(1)
+        .unwrap_err();
+
+        assert!(err
+            .to_string()
+            .starts_with("failed to read does/not/exist.txt"));
+    }
+
+    #[test]
+    fn read_input_from_cache() {
+        let dir = std::env::temp_dir().join(format!("aoc-input-cache-{}", std::process::id()));
+        let args = InputArgs {
+            input: None,
+            cache: cache_args(Some(dir.clone())),
+        };
+
+        let missing = args.read(3).unwrap_err();
+        cache::Cache::new(&dir)
+            .write(YEAR, 3, "default", "vJrw\n")
+            .unwrap();
+        let input = args.read(3);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(
+            missing.to_string(),
+            "no cached input for day 3 (default), run `aoc fetch 3` or pass an input file"
+        );
+        assert_eq!(input.unwrap(), "vJrw\n");
+    }
+
+    #[test]
+    fn solve_reports_parse_errors() {
+        let err = solve::<Sum>("1\nx\n", &Part::ALL).unwrap_err();
+        assert_eq!(err.to_string(), "line 2, column 1: not a number");
+    }
+}
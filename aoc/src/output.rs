@@ -0,0 +1,128 @@
+//! Printing answers, either for people or as JSON for scripts.
+//!
+//! The JSON form is an array with one object per answered part:
+//!
+//! ```json
+//! [
+//!   {
+//!     "day": 1,
+//!     "part": 1,
+//!     "answer": "24000",
+//!     "parse_ns": 5230,
+//!     "solve_ns": 410,
+//!     "input_sha256": "e3b0c442..."
+//!   }
+//! ]
+//! ```
+//!
+//! `parse_ns` is the time taken to parse the input, shared by all parts of the same run, and
+//! `solve_ns` the time taken by the part itself.
+
+use std::fmt::Write;
+use std::time::Duration;
+
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+
+use crate::{Answer, Part};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, clap::ValueEnum)]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Record<'a> {
+    pub day: u8,
+    pub part: Part,
+    pub answer: &'a str,
+    #[serde(rename = "parse_ns", serialize_with = "crate::bench::nanos")]
+    pub parse: Duration,
+    #[serde(rename = "solve_ns", serialize_with = "crate::bench::nanos")]
+    pub solve: Duration,
+    pub input_sha256: &'a str,
+}
+
+/// Hex-encoded SHA-256 of `input`, to tell which input an answer is for without storing it.
+pub fn input_hash(input: &str) -> String {
+    Sha256::digest(input)
+        .iter()
+        .fold(String::new(), |mut hex, byte| {
+            write!(hex, "{byte:02x}").unwrap();
+            hex
+        })
+}
+
+pub fn records<'a>(day: u8, input_sha256: &'a str, answers: &'a [Answer]) -> Vec<Record<'a>> {
+    answers
+        .iter()
+        .map(|answer| Record {
+            day,
+            part: answer.part,
+            answer: &answer.answer,
+            parse: answer.parse,
+            solve: answer.solve,
+            input_sha256,
+        })
+        .collect()
+}
+
+/// Prints answers as returned by [`solve`](crate::solve) for `input`. As text, multi-line
+/// answers start on their own line.
+pub fn print(format: Format, day: u8, input: &str, answers: &[Answer]) {
+    match format {
+        Format::Text => {
+            for Answer { part, answer, .. } in answers {
+                if answer.contains('\n') {
+                    print!("day {day} part {part}:\n{answer}");
+                } else {
+                    println!("day {day} part {part}: {answer}");
+                }
+            }
+        }
+        Format::Json => {
+            let hash = input_hash(input);
+            let records = records(day, &hash, answers);
+            println!("{}", serde_json::to_string_pretty(&records).unwrap());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hash_is_hex_sha256() {
+        assert_eq!(
+            input_hash(""),
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+    }
+
+    #[test]
+    fn records_serialize_to_stable_schema() {
+        let answers = [Answer {
+            part: Part::Two,
+            answer: "45000".to_string(),
+            parse: Duration::from_nanos(100),
+            solve: Duration::from_nanos(20),
+        }];
+
+        let json = serde_json::to_value(records(1, "abc", &answers)).unwrap();
+
+        assert_eq!(
+            json,
+            serde_json::json!([{
+                "day": 1,
+                "part": 2,
+                "answer": "45000",
+                "parse_ns": 100,
+                "solve_ns": 20,
+                "input_sha256": "abc",
+            }])
+        );
+    }
+}
@@ -0,0 +1,341 @@
+//! Checks solutions against the answers recorded in each day's `answers.json`.
+//!
+//! The file lists inputs with the expected answer for each part. An input is either a file,
+//! relative to the day's crate directory, or the real input of an account in the input cache:
+//!
+//! ```json
+//! [
+//!   { "input": "src/sample-input.txt", "part1": "21", "part2": "8" },
+//!   { "cached": { "account": "work" }, "part1": "1825", "part2": "235200" },
+//!   { "cached": {}, "part1": "1849" }
+//! ]
+//! ```
+//!
+//! A cached input may also name its `year`, which defaults to [`YEAR`]; without an `account` it is
+//! the input of the account verification runs as (`--account`).
+//!
+//! Either part may be omitted; inputs that are not present, such as another account's or one
+//! never fetched, are skipped rather than failed.
+//!
+//! To record the answers for a real input, fetch it with `aoc fetch <day>`, solve it with
+//! `aoc run <day>`, and once the website has accepted the answers, add them to the day's
+//! `answers.json` as a `cached` entry for the account.
+
+use std::fmt::{self, Display, Formatter, Write};
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+use crate::cache::{CacheArgs, YEAR};
+use crate::{Error, Part, Solver};
+
+pub const ANSWERS_FILE: &str = "answers.json";
+
+/// Where the input of an [`Expected`] comes from.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Source {
+    /// A file, relative to the day's crate directory.
+    Input(PathBuf),
+    /// The day's input in the cache, as stored by `aoc fetch`.
+    Cached(Cached),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Cached {
+    /// [`YEAR`] when omitted.
+    pub year: Option<u16>,
+    /// The account verification runs as when omitted.
+    pub account: Option<String>,
+}
+
+impl Source {
+    /// The input for `day`, or why there is none to check.
+    fn read(&self, day: u8, day_dir: &Path, cache: &CacheArgs) -> Result<String, Outcome> {
+        match self {
+            Self::Input(path) => std::fs::read_to_string(day_dir.join(path))
+                .map_err(|err| Outcome::Skipped(err.to_string())),
+            Self::Cached(cached) => {
+                let year = cached.year.unwrap_or(YEAR);
+                let account = cached.account.as_deref().unwrap_or(&cache.account);
+                let error = |err: Error| Outcome::Error(err.to_string());
+
+                match cache.cache().map_err(error)?.read(year, day, account) {
+                    Ok(Some(input)) => Ok(input),
+                    Ok(None) => Err(Outcome::Skipped(format!(
+                        "not cached, run `aoc fetch {day} --account {account}`"
+                    ))),
+                    Err(err) => Err(error(err)),
+                }
+            }
+        }
+    }
+}
+
+impl Display for Source {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Input(path) => write!(f, "{}", path.display()),
+            Self::Cached(Cached { year, account }) => {
+                write!(f, "cached {} input", year.unwrap_or(YEAR))?;
+                match account {
+                    Some(account) => write!(f, " ({account})"),
+                    None => Ok(()),
+                }
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Expected {
+    #[serde(flatten)]
+    pub source: Source,
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+impl Expected {
+    fn parts(&self) -> Vec<(Part, &str)> {
+        let part1 = self.part1.as_deref().map(|answer| (Part::One, answer));
+        let part2 = self.part2.as_deref().map(|answer| (Part::Two, answer));
+        part1.into_iter().chain(part2).collect()
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Pass,
+    Fail { expected: String, actual: String },
+    Skipped(String),
+    Error(String),
+}
+
+#[derive(Debug)]
+pub struct Check {
+    pub source: Source,
+    pub part: Option<Part>,
+    pub outcome: Outcome,
+}
+
+pub fn load_answers(day_dir: &Path) -> Result<Vec<Expected>, Error> {
+    let path = day_dir.join(ANSWERS_FILE);
+    let json = std::fs::read_to_string(&path)
+        .map_err(|err| format!("failed to read {}: {err}", path.display()))?;
+
+    serde_json::from_str(&json).map_err(|err| format!("invalid {}: {err}", path.display()).into())
+}
+
+/// Runs `solve` over every recorded input of `day`, whose crate is in `day_dir`, reading cached
+/// inputs from `cache`.
+pub fn verify(
+    solve: Solver,
+    day: u8,
+    day_dir: &Path,
+    cache: &CacheArgs,
+) -> Result<Vec<Check>, Error> {
+    let mut checks = vec![];
+
+    for expected in load_answers(day_dir)? {
+        let input = match expected.source.read(day, day_dir, cache) {
+            Ok(input) => input,
+            Err(outcome) => {
+                checks.push(Check {
+                    source: expected.source,
+                    part: None,
+                    outcome,
+                });
+                continue;
+            }
+        };
+
+        let parts = expected.parts();
+        let selected = parts.iter().map(|&(part, _)| part).collect::<Vec<_>>();
+
+        match solve(&input, &selected) {
+            Ok(answers) => {
+                for ((part, expected_answer), actual) in parts.into_iter().zip(answers) {
+                    checks.push(Check {
+                        source: expected.source.clone(),
+                        part: Some(part),
+                        outcome: compare(expected_answer, actual.answer),
+                    });
+                }
+            }
+            Err(err) => checks.push(Check {
+                source: expected.source,
+                part: None,
+                outcome: Outcome::Error(err.to_string()),
+            }),
+        }
+    }
+
+    Ok(checks)
+}
+
+fn compare(expected: &str, actual: String) -> Outcome {
+    if expected == actual {
+        Outcome::Pass
+    } else {
+        Outcome::Fail {
+            expected: expected.to_owned(),
+            actual,
+        }
+    }
+}
+
+/// Line-by-line diff of two answers, marking expected lines with `-` and actual ones with `+`.
+pub fn diff(expected: &str, actual: &str) -> String {
+    let expected = expected.lines().collect::<Vec<_>>();
+    let actual = actual.lines().collect::<Vec<_>>();
+    let mut output = String::new();
+
+    for idx in 0..expected.len().max(actual.len()) {
+        match (expected.get(idx), actual.get(idx)) {
+            (Some(e), Some(a)) if e == a => writeln!(output, "  {e}").unwrap(),
+            (e, a) => {
+                if let Some(e) = e {
+                    writeln!(output, "- {e}").unwrap();
+                }
+                if let Some(a) = a {
+                    writeln!(output, "+ {a}").unwrap();
+                }
+            }
+        }
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Answer;
+    use std::time::Duration;
+
+    fn solve_lengths(input: &str, parts: &[Part]) -> Result<Vec<Answer>, Error> {
+        if input.is_empty() {
+            return Err("empty input".into());
+        }
+
+        Ok(parts
+            .iter()
+            .map(|&part| Answer {
+                part,
+                answer: match part {
+                    Part::One => input.lines().count().to_string(),
+                    Part::Two => input.len().to_string(),
+                },
+                parse: Duration::ZERO,
+                solve: Duration::ZERO,
+            })
+            .collect())
+    }
+
+    fn no_cache() -> CacheArgs {
+        CacheArgs {
+            cache_dir: Some("does/not/exist".into()),
+            account: "default".to_string(),
+        }
+    }
+
+    fn day_dir(name: &str, answers: &str, inputs: &[(&str, &str)]) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-verify-{name}-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join(ANSWERS_FILE), answers).unwrap();
+
+        for (path, content) in inputs {
+            std::fs::write(dir.join(path), content).unwrap();
+        }
+
+        dir
+    }
+
+    #[test]
+    fn verify_reports_each_part() {
+        let dir = day_dir(
+            "parts",
+            r#"[{ "input": "a.txt", "part1": "2", "part2": "7" }]"#,
+            &[("a.txt", "ab\ncd\n")],
+        );
+
+        let checks = verify(solve_lengths, 1, &dir, &no_cache()).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(checks.len(), 2);
+        assert_eq!(checks[0].part, Some(Part::One));
+        assert_eq!(checks[0].outcome, Outcome::Pass);
+        assert_eq!(checks[1].part, Some(Part::Two));
+        assert_eq!(
+            checks[1].outcome,
+            Outcome::Fail {
+                expected: "7".to_string(),
+                actual: "6".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn verify_skips_missing_inputs_and_reports_errors() {
+        let dir = day_dir(
+            "missing",
+            r#"[{ "input": "missing.txt", "part1": "1" }, { "input": "empty.txt", "part2": "0" }]"#,
+            &[("empty.txt", "")],
+        );
+
+        let checks = verify(solve_lengths, 1, &dir, &no_cache()).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert!(matches!(checks[0].outcome, Outcome::Skipped(_)));
+        assert_eq!(checks[1].outcome, Outcome::Error("empty input".to_string()));
+    }
+
+    #[test]
+    fn verify_reads_cached_inputs_by_account() {
+        let dir = day_dir(
+            "cached",
+            r#"[
+                { "cached": {}, "part1": "1" },
+                { "cached": { "account": "work" }, "part1": "2" },
+                { "cached": { "year": 2021, "account": "work" }, "part2": "5" }
+            ]"#,
+            &[],
+        );
+        let cache = CacheArgs {
+            cache_dir: Some(dir.join("cache")),
+            account: "home".to_string(),
+        };
+        crate::cache::Cache::new(dir.join("cache"))
+            .write(YEAR, 4, "home", "abcd\n")
+            .unwrap();
+
+        let checks = verify(solve_lengths, 4, &dir, &cache).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        let outcomes = checks
+            .iter()
+            .map(|check| (check.source.to_string(), &check.outcome))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            outcomes,
+            [
+                ("cached 2022 input".to_string(), &Outcome::Pass),
+                (
+                    "cached 2022 input (work)".to_string(),
+                    &Outcome::Skipped("not cached, run `aoc fetch 4 --account work`".to_string())
+                ),
+                (
+                    "cached 2021 input (work)".to_string(),
+                    &Outcome::Skipped("not cached, run `aoc fetch 4 --account work`".to_string())
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn diff_marks_changed_lines() {
+        assert_eq!(diff("#.\n.#\n", "#.\n##\n"), "  #.\n- .#\n+ ##\n");
+        assert_eq!(diff("1", "2\n3"), "- 1\n+ 2\n+ 3\n");
+    }
+}
@@ -0,0 +1,98 @@
+//! Downloads puzzle inputs from the Advent of Code website.
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+const USER_AGENT: &str = "github.com/pazustep/aoc2022 input fetcher";
+
+/// Fetches the input of `day` for the account logged in with `session`.
+pub fn fetch_input(
+    base_url: &str,
+    year: u16,
+    day: u8,
+    session: &str,
+) -> Result<String, aoc::Error> {
+    let url = format!("{}/{year}/day/{day}/input", base_url.trim_end_matches('/'));
+
+    let response = ureq::get(&url)
+        .set("Cookie", &format!("session={session}"))
+        .set("User-Agent", USER_AGENT)
+        .call()
+        .map_err(|err| match err {
+            ureq::Error::Status(code, _) => format!("{url} returned HTTP {code}"),
+            ureq::Error::Transport(err) => format!("failed to fetch {url}: {err}"),
+        })?;
+
+    Ok(response.into_string()?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::thread::JoinHandle;
+
+    /// Serves one request with `status` and `body`, returning the request head it received.
+    fn serve_once(status: &str, body: &'static str) -> (String, JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let status = status.to_owned();
+
+        let handle = std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = String::new();
+
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if line == "\r\n" || line.is_empty() {
+                    break;
+                }
+                request.push_str(&line);
+            }
+
+            write!(
+                stream,
+                "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+
+            request
+        });
+
+        (base_url, handle)
+    }
+
+    #[test]
+    fn fetch_sends_session_cookie() {
+        let (base_url, server) = serve_once("200 OK", "1000\n2000\n");
+
+        let input = fetch_input(&base_url, 2022, 1, "abc123").unwrap();
+        let request = server.join().unwrap();
+
+        assert_eq!(input, "1000\n2000\n");
+        assert!(
+            request.starts_with("GET /2022/day/1/input HTTP/1.1\r\n"),
+            "{request}"
+        );
+        assert!(
+            request.contains("\r\nCookie: session=abc123\r\n"),
+            "{request}"
+        );
+    }
+
+    #[test]
+    fn fetch_reports_http_errors() {
+        let (base_url, server) = serve_once("400 Bad Request", "Please log in");
+
+        let err = fetch_input(&format!("{base_url}/"), 2022, 5, "expired").unwrap_err();
+        server.join().unwrap();
+
+        assert_eq!(
+            err.to_string(),
+            format!("{base_url}/2022/day/5/input returned HTTP 400")
+        );
+    }
+}
@@ -0,0 +1,361 @@
+use std::path::{Path, PathBuf};
+
+use aoc::bench::Report;
+use aoc::cache::{CacheArgs, YEAR};
+use aoc::output::Format;
+use aoc::verify::{Check, Outcome};
+use aoc::{Day, InputArgs, Part};
+use clap::{Parser, Subcommand};
+
+mod days;
+mod fetch;
+mod scaffold;
+
+use days::DAYS;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2022 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve one day's puzzle
+    Run {
+        /// Day number, of a day with a solution
+        day: u8,
+
+        /// Only solve this part (1 or 2); both parts are solved when omitted
+        #[arg(long)]
+        part: Option<Part>,
+
+        #[command(flatten)]
+        input: InputArgs,
+
+        /// How to print the answers
+        #[arg(long, value_enum, default_value_t)]
+        format: Format,
+    },
+    /// Check answers against each day's answers.json
+    Verify {
+        /// Only verify this day; every day is verified when omitted
+        day: Option<u8>,
+
+        /// Workspace directory containing the day crates
+        #[arg(long, default_value = concat!(env!("CARGO_MANIFEST_DIR"), "/.."))]
+        root: PathBuf,
+
+        /// Where answers recorded for cached inputs find them
+        #[command(flatten)]
+        cache: CacheArgs,
+    },
+    /// Time parsing and each part over repeated runs
+    Bench {
+        /// Only benchmark this day; every day with an input is benchmarked when omitted
+        day: Option<u8>,
+
+        /// Number of runs per day
+        #[arg(short = 'n', long, default_value_t = 10)]
+        runs: usize,
+
+        /// How to print the results
+        #[arg(long, value_enum, default_value_t)]
+        format: Format,
+
+        /// Input file, relative to each day's crate directory; the cached input is used when
+        /// omitted
+        #[arg(long)]
+        input: Option<PathBuf>,
+
+        /// Workspace directory containing the day crates
+        #[arg(long, default_value = concat!(env!("CARGO_MANIFEST_DIR"), "/.."))]
+        root: PathBuf,
+
+        #[command(flatten)]
+        cache: CacheArgs,
+    },
+    /// Download a day's input into the cache
+    Fetch {
+        /// Day number, 1 to 25
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        /// Session cookie of the logged-in account
+        #[arg(long, env = "AOC_SESSION", hide_env_values = true)]
+        session: String,
+
+        /// Website to download from
+        #[arg(long, env = "AOC_BASE_URL", default_value = fetch::DEFAULT_BASE_URL)]
+        base_url: String,
+
+        #[arg(long, default_value_t = YEAR)]
+        year: u16,
+
+        /// Download again even if the input is already cached
+        #[arg(long)]
+        force: bool,
+
+        #[command(flatten)]
+        cache: CacheArgs,
+    },
+    /// Generate the crate for a new day
+    New {
+        /// Day number, 1 to 25
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        /// Workspace directory to create the crate in
+        #[arg(long, default_value = concat!(env!("CARGO_MANIFEST_DIR"), "/.."))]
+        root: PathBuf,
+    },
+}
+
+fn day(number: u8) -> Option<Day> {
+    DAYS.iter().find(|day| day.number == number).copied()
+}
+
+/// Every day with a solution, in order.
+fn all_days() -> Vec<u8> {
+    DAYS.iter().map(|day| day.number).collect()
+}
+
+fn main() {
+    let cli = Cli::parse();
+
+    match cli.command {
+        Command::Run {
+            day,
+            part,
+            input,
+            format,
+        } => {
+            let Some(Day { solve, .. }) = self::day(day) else {
+                eprintln!("no solution for day {day}");
+                std::process::exit(2);
+            };
+
+            let parts = match part {
+                Some(part) => vec![part],
+                None => Part::ALL.to_vec(),
+            };
+
+            match input
+                .read(day)
+                .and_then(|input| Ok((solve(&input, &parts)?, input)))
+            {
+                Ok((answers, input)) => aoc::output::print(format, day, &input, &answers),
+                Err(err) => {
+                    eprintln!("error: {err}");
+                    std::process::exit(1);
+                }
+            }
+        }
+        Command::Verify { day, root, cache } => {
+            let days = match day {
+                Some(day) => vec![day],
+                None => all_days(),
+            };
+
+            if !verify(&days, &root, &cache) {
+                std::process::exit(1);
+            }
+        }
+        Command::Bench {
+            day,
+            runs,
+            format,
+            input,
+            root,
+            cache,
+        } => {
+            let days = match day {
+                Some(day) => vec![day],
+                None => all_days(),
+            };
+
+            let (reports, failed) = bench(&days, runs, input.as_deref(), &root, &cache);
+
+            match format {
+                Format::Text => print_bench(&reports),
+                Format::Json => println!("{}", serde_json::to_string_pretty(&reports).unwrap()),
+            }
+
+            if failed > 0 {
+                eprintln!("{failed} of {} days could not be benchmarked", days.len());
+                std::process::exit(1);
+            }
+        }
+        Command::Fetch {
+            day,
+            session,
+            base_url,
+            year,
+            force,
+            cache,
+        } => {
+            if let Err(err) = fetch(day, &session, &base_url, year, force, &cache) {
+                eprintln!("error: {err}");
+                std::process::exit(1);
+            }
+        }
+        Command::New { day, root } => match scaffold::scaffold(&root, day) {
+            Ok(scaffolded) => {
+                for path in scaffolded.created {
+                    println!("created {}", path.display());
+                }
+                for path in scaffolded.updated {
+                    println!("updated {}", path.display());
+                }
+            }
+            Err(err) => {
+                eprintln!("error: {err}");
+                std::process::exit(1);
+            }
+        },
+    }
+}
+
+fn fetch(
+    day: u8,
+    session: &str,
+    base_url: &str,
+    year: u16,
+    force: bool,
+    cache_args: &CacheArgs,
+) -> Result<(), aoc::Error> {
+    let cache = cache_args.cache()?;
+    let account = &cache_args.account;
+
+    if !force && cache.read(year, day, account)?.is_some() {
+        let path = cache.path(year, day, account)?;
+        println!("{} already cached", path.display());
+        return Ok(());
+    }
+
+    let input = fetch::fetch_input(base_url, year, day, session)?;
+    let path = cache.write(year, day, account, &input)?;
+    println!("saved {}", path.display());
+    Ok(())
+}
+
+/// Benchmarks each of `days`, returning the reports and how many days were skipped or failed.
+fn bench(
+    days: &[u8],
+    runs: usize,
+    input: Option<&Path>,
+    root: &Path,
+    cache: &CacheArgs,
+) -> (Vec<Report>, usize) {
+    let (mut reports, mut failed) = (vec![], 0);
+
+    for &number in days {
+        let Some(Day { time, .. }) = day(number) else {
+            eprintln!("no solution for day {number}");
+            failed += 1;
+            continue;
+        };
+
+        let input = InputArgs {
+            input: input.map(|input| root.join(format!("day{number}")).join(input)),
+            cache: cache.clone(),
+        };
+
+        let input = match input.read(number) {
+            Ok(input) => input,
+            Err(err) => {
+                eprintln!("day {number}: skipped, {err}");
+                failed += 1;
+                continue;
+            }
+        };
+
+        match aoc::bench::bench(number, time, &input, runs) {
+            Ok(report) => reports.push(report),
+            Err(err) => {
+                eprintln!("day {number}: {err}");
+                failed += 1;
+            }
+        }
+    }
+
+    (reports, failed)
+}
+
+fn print_bench(reports: &[Report]) {
+    println!(
+        "{:>3}  {:<5}  {:>12}  {:>12}  {:>12}",
+        "day", "phase", "min", "median", "max"
+    );
+
+    for report in reports {
+        for (phase, stats) in [
+            ("parse", report.parse),
+            ("part1", report.part1),
+            ("part2", report.part2),
+        ] {
+            println!(
+                "{:>3}  {:<5}  {:>12.2?}  {:>12.2?}  {:>12.2?}",
+                report.day, phase, stats.min, stats.median, stats.max
+            );
+        }
+    }
+}
+
+/// Verifies and reports on each of `days`, returning whether all checks passed.
+fn verify(days: &[u8], root: &Path, cache: &CacheArgs) -> bool {
+    let (mut passed, mut failed, mut skipped) = (0, 0, 0);
+
+    for &day in days {
+        let Some(Day { solve, .. }) = self::day(day) else {
+            eprintln!("no solution for day {day}");
+            failed += 1;
+            continue;
+        };
+
+        let checks = match aoc::verify::verify(solve, day, &root.join(format!("day{day}")), cache) {
+            Ok(checks) => checks,
+            Err(err) => {
+                eprintln!("day {day}: {err}");
+                failed += 1;
+                continue;
+            }
+        };
+
+        for Check {
+            source,
+            part,
+            outcome,
+        } in checks
+        {
+            let label = match part {
+                Some(part) => format!("day {day} {source} part {part}"),
+                None => format!("day {day} {source}"),
+            };
+
+            match outcome {
+                Outcome::Pass => {
+                    passed += 1;
+                    println!("{label}: ok");
+                }
+                Outcome::Fail { expected, actual } => {
+                    failed += 1;
+                    println!("{label}: FAIL");
+                    print!("{}", aoc::verify::diff(&expected, &actual));
+                }
+                Outcome::Skipped(reason) => {
+                    skipped += 1;
+                    println!("{label}: skipped ({reason})");
+                }
+                Outcome::Error(err) => {
+                    failed += 1;
+                    println!("{label}: ERROR {err}");
+                }
+            }
+        }
+    }
+
+    println!("{passed} passed, {failed} failed, {skipped} skipped");
+    failed == 0
+}
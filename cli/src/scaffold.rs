@@ -0,0 +1,213 @@
+//! Generates the crate for a new day, laid out like the existing ones.
+
+use std::path::{Path, PathBuf};
+
+const CARGO_TOML: &str = r#"[package]
+name = "day{day}"
+version = "0.1.0"
+edition = "2021"
+
+# See more keys and their definitions at https://doc.rust-lang.org/cargo/reference/manifest.html
+
+[dependencies]
+aoc.workspace = true
+
+[dev-dependencies]
+proptest.workspace = true
+"#;
+
+const MAIN_RS: &str = r#"use std::process::ExitCode;
+
+use aoc::Part;
+use day{day}::Day{day};
+
+fn main() -> ExitCode {
+    aoc::run::<Day{day}>(&Part::ALL)
+}
+"#;
+
+const LIB_RS: &str = r#"use aoc::Solution;
+
+pub struct Day{day};
+
+impl Solution for Day{day} {
+    const DAY: u8 = {day};
+
+    type Input = Vec<String>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, aoc::Error> {
+        aoc::parse_lines(input, |line| Ok(line.to_owned()))
+    }
+
+    fn part1(_input: &Self::Input) -> Result<Self::Part1, aoc::Error> {
+        Err("part 1 is not solved yet".into())
+    }
+
+    fn part2(_input: &Self::Input) -> Result<Self::Part2, aoc::Error> {
+        Err("part 2 is not solved yet".into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day{day};
+    use aoc::Solution;
+
+    #[test]
+    #[ignore = "fill in sample-input.txt and its answer"]
+    fn test_part1_with_sample_input() {
+        let input = Day{day}::parse(include_str!("sample-input.txt")).unwrap();
+        assert_eq!(Day{day}::part1(&input).unwrap(), 0);
+    }
+}
+"#;
+
+const ANSWERS_JSON: &str = r#"[{ "input": "src/sample-input.txt" }]
+"#;
+
+/// What [`scaffold`] did.
+#[derive(Debug)]
+pub struct Scaffolded {
+    pub created: Vec<PathBuf>,
+    pub updated: Vec<PathBuf>,
+}
+
+/// Writes the `day<day>` crate under `root` and registers it with the `aoc` binary in
+/// `root/cli`. Refuses to touch an existing crate directory.
+pub fn scaffold(root: &Path, day: u8) -> Result<Scaffolded, aoc::Error> {
+    let dir = root.join(format!("day{day}"));
+    if dir.exists() {
+        return Err(format!("{} already exists", dir.display()).into());
+    }
+
+    // Worked out up front, so that a missing file leaves the workspace untouched.
+    let cargo_toml = root.join("cli/Cargo.toml");
+    let days_rs = root.join("cli/src/days.rs");
+    let registrations = [
+        (
+            register_in(&cargo_toml, day, dependency_day, |day| {
+                format!("day{day} = {{ path = \"../day{day}\" }}")
+            })?,
+            cargo_toml,
+        ),
+        (
+            register_in(&days_rs, day, table_day, |day| {
+                format!("    Day::of::<day{day}::Day{day}>(),")
+            })?,
+            days_rs,
+        ),
+    ];
+
+    let files = [
+        ("Cargo.toml", CARGO_TOML),
+        ("answers.json", ANSWERS_JSON),
+        ("src/main.rs", MAIN_RS),
+        ("src/lib.rs", LIB_RS),
+        ("src/sample-input.txt", ""),
+    ];
+
+    std::fs::create_dir_all(dir.join("src"))?;
+
+    let mut created = vec![];
+    for (name, template) in files {
+        let path = dir.join(name);
+        write(&path, &template.replace("{day}", &day.to_string()))?;
+        created.push(path);
+    }
+
+    let mut updated = vec![];
+    for (text, path) in registrations {
+        write(&path, &text)?;
+        updated.push(path);
+    }
+
+    Ok(Scaffolded { created, updated })
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), aoc::Error> {
+    std::fs::write(path, contents)
+        .map_err(|err| format!("failed to write {}: {err}", path.display()).into())
+}
+
+/// The contents of the file at `path` with `day` registered in it.
+fn register_in(
+    path: &Path,
+    day: u8,
+    day_of: fn(&str) -> Option<u8>,
+    entry: fn(u8) -> String,
+) -> Result<String, aoc::Error> {
+    let text = std::fs::read_to_string(path)
+        .map_err(|err| format!("failed to read {}: {err}", path.display()))?;
+
+    register(&text, day, &entry(day), day_of)
+        .ok_or_else(|| format!("no days registered in {}", path.display()).into())
+}
+
+/// The day of a `dayN = { path = "../dayN" }` dependency line.
+fn dependency_day(line: &str) -> Option<u8> {
+    let (name, _) = line.split_once(" = ")?;
+    name.strip_prefix("day")?.parse().ok()
+}
+
+/// The day of a `Day::of::<dayN::DayN>(),` line of the day table.
+fn table_day(line: &str) -> Option<u8> {
+    let name = line.trim().strip_prefix("Day::of::<day")?;
+    let (day, _) = name.split_once("::")?;
+    day.parse().ok()
+}
+
+/// `text` with `entry` added as a line among those `day_of` finds a day in, keeping them in
+/// order of day, or `None` if there are no such lines. Already registered days are left alone.
+fn register(text: &str, day: u8, entry: &str, day_of: fn(&str) -> Option<u8>) -> Option<String> {
+    let mut lines = text.lines().collect::<Vec<_>>();
+    let days = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, line)| Some((idx, day_of(line)?)))
+        .collect::<Vec<_>>();
+
+    let &(first, _) = days.first()?;
+    if days.iter().any(|&(_, registered)| registered == day) {
+        return Some(text.to_owned());
+    }
+
+    let at = days
+        .iter()
+        .rev()
+        .find(|&&(_, registered)| registered < day)
+        .map_or(first, |&(idx, _)| idx + 1);
+    lines.insert(at, entry);
+
+    Some(lines.join("\n") + "\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn register_keeps_days_in_order() {
+        let text = "[dependencies]\naoc.workspace = true\nday1 = {}\nday3 = {}\n\n[x]\n";
+
+        let register = |day| register(text, day, &format!("day{day} = {{}}"), dependency_day);
+        assert_eq!(
+            register(2).unwrap(),
+            "[dependencies]\naoc.workspace = true\nday1 = {}\nday2 = {}\nday3 = {}\n\n[x]\n"
+        );
+        assert_eq!(
+            register(11).unwrap(),
+            "[dependencies]\naoc.workspace = true\nday1 = {}\nday3 = {}\nday11 = {}\n\n[x]\n"
+        );
+        assert_eq!(register(3).unwrap(), text);
+    }
+
+    #[test]
+    fn register_needs_a_day_to_go_next_to() {
+        assert_eq!(
+            register("pub const DAYS: &[Day] = &[];\n", 1, "", table_day),
+            None
+        );
+    }
+}
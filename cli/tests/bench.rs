@@ -0,0 +1,86 @@
+use std::process::Command;
+
+#[test]
+fn bench_emits_json_per_phase() {
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args([
+            "bench",
+            "8",
+            "-n",
+            "3",
+            "--format",
+            "json",
+            "--input",
+            "src/sample-input.txt",
+        ])
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+
+    let reports: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let report = &reports[0];
+    assert_eq!(report["day"], 8);
+    assert_eq!(report["runs"], 3);
+
+    for phase in ["parse", "part1", "part2"] {
+        let min = report[phase]["min_ns"].as_u64().unwrap();
+        let median = report[phase]["median_ns"].as_u64().unwrap();
+        let max = report[phase]["max_ns"].as_u64().unwrap();
+        assert!(min <= median && median <= max, "{phase}: {report}");
+    }
+}
+
+#[test]
+fn bench_prints_a_table_by_default() {
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["bench", "8", "-n", "1", "--input", "src/sample-input.txt"])
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let phases = stdout
+        .lines()
+        .map(|line| line.split_whitespace().take(2).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        phases,
+        [
+            ["day", "phase"],
+            ["8", "parse"],
+            ["8", "part1"],
+            ["8", "part2"]
+        ]
+    );
+}
+
+#[test]
+fn bench_fails_when_a_day_cannot_be_benchmarked() {
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["bench", "42", "--format", "json"])
+        .output()
+        .unwrap();
+
+    assert!(!output.status.success());
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "[]\n");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args([
+            "bench",
+            "6",
+            "-n",
+            "1",
+            "--input",
+            "../day8/src/sample-input.txt",
+        ])
+        .output()
+        .unwrap();
+
+    assert!(!output.status.success());
+    assert!(
+        String::from_utf8_lossy(&output.stderr).starts_with("day 6: "),
+        "{output:?}"
+    );
+}
@@ -0,0 +1,71 @@
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+use std::process::Command;
+
+const INPUT: &str = "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000\n";
+
+/// Answers a single request with the day 1 sample input.
+fn serve_input() -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+    std::thread::spawn(move || {
+        let (mut stream, _) = listener.accept().unwrap();
+        let mut reader = BufReader::new(stream.try_clone().unwrap());
+        let mut line = String::new();
+        while reader.read_line(&mut line).unwrap() > 2 {
+            line.clear();
+        }
+
+        write!(
+            stream,
+            "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{INPUT}",
+            INPUT.len()
+        )
+        .unwrap();
+    });
+
+    base_url
+}
+
+#[test]
+fn fetched_input_is_used_by_run() {
+    let cache_dir = std::env::temp_dir().join(format!("aoc-fetch-{}", std::process::id()));
+    let aoc = || {
+        let mut command = Command::new(env!("CARGO_BIN_EXE_aoc"));
+        command
+            .env("AOC_CACHE_DIR", &cache_dir)
+            .env("AOC_ACCOUNT", "test");
+        command
+    };
+
+    let fetch = aoc()
+        .args(["fetch", "1", "--session", "abc123"])
+        .args(["--base-url", &serve_input()])
+        .output()
+        .unwrap();
+    let run = aoc().args(["run", "1"]).output().unwrap();
+    let cached = std::fs::read_to_string(cache_dir.join("2022/day1/test.txt"));
+    std::fs::remove_dir_all(&cache_dir).unwrap();
+
+    assert!(fetch.status.success(), "{fetch:?}");
+    assert_eq!(cached.unwrap(), INPUT);
+    assert_eq!(
+        String::from_utf8_lossy(&run.stdout),
+        "day 1 part 1: 24000\nday 1 part 2: 45000\n"
+    );
+}
+
+#[test]
+fn run_without_cached_input_suggests_fetch() {
+    let cache_dir = std::env::temp_dir().join(format!("aoc-fetch-empty-{}", std::process::id()));
+
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["run", "3"])
+        .env("AOC_CACHE_DIR", &cache_dir)
+        .output()
+        .unwrap();
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("run `aoc fetch 3`"));
+}
@@ -0,0 +1,69 @@
+use std::path::Path;
+use std::process::Command;
+
+#[test]
+fn new_generates_day_crate() {
+    let root = std::env::temp_dir().join(format!("aoc-cli-new-{}", std::process::id()));
+    std::fs::create_dir_all(root.join("cli/src")).unwrap();
+    for file in ["Cargo.toml", "src/days.rs"] {
+        std::fs::copy(
+            Path::new(env!("CARGO_MANIFEST_DIR")).join(file),
+            root.join("cli").join(file),
+        )
+        .unwrap();
+    }
+
+    let new = || {
+        Command::new(env!("CARGO_BIN_EXE_aoc"))
+            .args(["new", "11", "--root"])
+            .arg(&root)
+            .output()
+            .unwrap()
+    };
+
+    let first = new();
+    let second = new();
+    let day = root.join("day11");
+    let cargo_toml = std::fs::read_to_string(day.join("Cargo.toml")).unwrap();
+    let main_rs = std::fs::read_to_string(day.join("src/main.rs")).unwrap();
+    let lib_rs = std::fs::read_to_string(day.join("src/lib.rs")).unwrap();
+    let has_sample = day.join("src/sample-input.txt").is_file();
+    let answers = std::fs::read_to_string(day.join("answers.json")).unwrap();
+    let cli_cargo_toml = std::fs::read_to_string(root.join("cli/Cargo.toml")).unwrap();
+    let days_rs = std::fs::read_to_string(root.join("cli/src/days.rs")).unwrap();
+    std::fs::remove_dir_all(&root).unwrap();
+
+    assert!(first.status.success(), "{first:?}");
+    assert!(cargo_toml.contains("name = \"day11\""));
+    assert!(main_rs.contains("aoc::run::<Day11>(&Part::ALL)"));
+    assert!(lib_rs.contains("impl Solution for Day11 {\n    const DAY: u8 = 11;"));
+    assert!(lib_rs.contains("include_str!(\"sample-input.txt\")"));
+    assert!(has_sample);
+    assert!(answers.contains("src/sample-input.txt"));
+    assert!(cli_cargo_toml
+        .contains("day10 = { path = \"../day10\" }\nday11 = { path = \"../day11\" }\n"));
+    assert!(
+        days_rs.contains("    Day::of::<day10::Day10>(),\n    Day::of::<day11::Day11>(),\n];\n")
+    );
+
+    assert!(!second.status.success());
+    assert!(String::from_utf8_lossy(&second.stderr).contains("already exists"));
+}
+
+#[test]
+fn new_needs_the_cli_crate_to_register_with() {
+    let root = std::env::temp_dir().join(format!("aoc-cli-new-bare-{}", std::process::id()));
+    std::fs::create_dir_all(&root).unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["new", "12", "--root"])
+        .arg(&root)
+        .output()
+        .unwrap();
+    let created = root.join("day12").exists();
+    std::fs::remove_dir_all(&root).unwrap();
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("failed to read"));
+    assert!(!created);
+}
@@ -0,0 +1,42 @@
+use std::process::Command;
+
+#[test]
+fn run_prints_json_records() {
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["run", "6", "--format", "json"])
+        .arg(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/../day6/src/sample-input.txt"
+        ))
+        .output()
+        .unwrap();
+
+    assert!(output.status.success(), "{output:?}");
+
+    let records: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let records = records.as_array().unwrap();
+    assert_eq!(records.len(), 2);
+
+    for (record, (part, answer)) in records.iter().zip([(1, "7"), (2, "19")]) {
+        assert_eq!(record["day"], 6);
+        assert_eq!(record["part"], part);
+        assert_eq!(record["answer"], answer);
+        assert!(record["parse_ns"].is_u64(), "{record}");
+        assert!(record["solve_ns"].is_u64(), "{record}");
+        assert_eq!(record["input_sha256"].as_str().unwrap().len(), 64);
+    }
+}
+
+#[test]
+fn run_prints_text_by_default() {
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["run", "6", "--part", "1"])
+        .arg(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/../day6/src/sample-input.txt"
+        ))
+        .output()
+        .unwrap();
+
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "day 6 part 1: 7\n");
+}
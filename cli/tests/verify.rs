@@ -0,0 +1,82 @@
+use std::process::Command;
+
+#[test]
+fn sample_answers_match() {
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .arg("verify")
+        .output()
+        .unwrap();
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(output.status.success(), "{stdout}");
+    assert!(stdout.ends_with(", 0 failed, 0 skipped\n"), "{stdout}");
+}
+
+#[test]
+fn wrong_answer_fails_with_diff() {
+    let root = std::env::temp_dir().join(format!("aoc-cli-verify-{}", std::process::id()));
+    let day = root.join("day6");
+    std::fs::create_dir_all(&day).unwrap();
+    std::fs::write(day.join("input.txt"), "mjqjpqmgbljsphdztnvjfqwrcgsmlb\n").unwrap();
+    std::fs::write(
+        day.join("answers.json"),
+        r#"[{ "input": "input.txt", "part1": "7", "part2": "20" }]"#,
+    )
+    .unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["verify", "6", "--root"])
+        .arg(&root)
+        .output()
+        .unwrap();
+
+    std::fs::remove_dir_all(&root).unwrap();
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(!output.status.success());
+    assert!(stdout.contains("day 6 input.txt part 1: ok\n"), "{stdout}");
+    assert!(
+        stdout.contains("day 6 input.txt part 2: FAIL\n- 20\n+ 19\n"),
+        "{stdout}"
+    );
+}
+
+#[test]
+fn cached_input_is_verified_for_the_account() {
+    let root = std::env::temp_dir().join(format!("aoc-cli-verify-cached-{}", std::process::id()));
+    let day = root.join("day6");
+    let cache = root.join("cache");
+    std::fs::create_dir_all(&day).unwrap();
+    std::fs::create_dir_all(cache.join("2022/day6")).unwrap();
+    std::fs::write(
+        cache.join("2022/day6/work.txt"),
+        "mjqjpqmgbljsphdztnvjfqwrcgsmlb\n",
+    )
+    .unwrap();
+    std::fs::write(
+        day.join("answers.json"),
+        r#"[{ "cached": {}, "part1": "7", "part2": "19" }]"#,
+    )
+    .unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["verify", "6", "--account", "work", "--root"])
+        .arg(&root)
+        .arg("--cache-dir")
+        .arg(&cache)
+        .output()
+        .unwrap();
+
+    std::fs::remove_dir_all(&root).unwrap();
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(output.status.success(), "{stdout}");
+    assert_eq!(
+        stdout,
+        concat!(
+            "day 6 cached 2022 input part 1: ok\n",
+            "day 6 cached 2022 input part 2: ok\n",
+            "2 passed, 0 failed, 0 skipped\n",
+        )
+    );
+}
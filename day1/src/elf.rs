@@ -0,0 +1,339 @@
+use std::borrow::Borrow;
+use std::cmp::{Ordering, Reverse};
+use std::collections::BinaryHeap;
+use std::fmt::{self, Display, Formatter};
+use std::io::{BufRead, Lines};
+use std::num::IntErrorKind;
+
+/// One elf's inventory: the calories of each item it carries, in input order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Elf {
+    /// 1-based position of the elf in the input.
+    pub index: usize,
+    pub items: Vec<u64>,
+    total: u64,
+}
+
+impl Elf {
+    pub fn total(&self) -> u64 {
+        self.total
+    }
+}
+
+/// What to do about lines that are neither a calorie count nor blank.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, clap::ValueEnum)]
+pub enum Mode {
+    /// Fail, reporting every malformed line.
+    #[default]
+    Strict,
+    /// Skip them, leaving it to the caller to warn about them.
+    Lenient,
+}
+
+/// A line that is neither a calorie count nor blank.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Malformed {
+    pub line: usize,
+    pub content: String,
+}
+
+impl Malformed {
+    /// The 1-based column of the first character that is not a digit.
+    fn column(&self) -> usize {
+        self.content
+            .chars()
+            .position(|c| !c.is_ascii_digit())
+            .map_or(1, |idx| idx + 1)
+    }
+}
+
+impl From<&Malformed> for aoc::Error {
+    fn from(malformed: &Malformed) -> Self {
+        aoc::Error::parse(
+            malformed.line,
+            malformed.column(),
+            format!("not a calorie count: {:?}", malformed.content),
+        )
+    }
+}
+
+/// Reads elves one at a time from blank-line separated groups of calorie counts, so inputs of
+/// any size are parsed in constant memory.
+///
+/// Malformed lines are skipped rather than ending the elf they appear in, and collected for
+/// [`Elves::finish`] to report once all elves have been read.
+pub struct Elves<R> {
+    lines: Lines<R>,
+    line: usize,
+    next_index: usize,
+    mode: Mode,
+    malformed: Vec<Malformed>,
+}
+
+impl<R: BufRead> Elves<R> {
+    pub fn new(reader: R, mode: Mode) -> Self {
+        Self {
+            lines: reader.lines(),
+            line: 0,
+            next_index: 1,
+            mode,
+            malformed: vec![],
+        }
+    }
+
+    /// The malformed lines seen; in strict mode, an error listing them if there are any.
+    pub fn finish(self) -> Result<Vec<Malformed>, aoc::Error> {
+        match (self.mode, self.malformed.as_slice()) {
+            (Mode::Strict, [malformed]) => Err(malformed.into()),
+            (Mode::Strict, [_, ..]) => Err(self
+                .malformed
+                .iter()
+                .map(|malformed| aoc::Error::from(malformed).to_string())
+                .collect::<Vec<_>>()
+                .join("\n")
+                .into()),
+            _ => Ok(self.malformed),
+        }
+    }
+
+    fn elf(&mut self, items: Vec<u64>, total: u64) -> Elf {
+        let index = self.next_index;
+        self.next_index += 1;
+        Elf {
+            index,
+            items,
+            total,
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for Elves<R> {
+    type Item = Result<Elf, aoc::Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut items = vec![];
+        let mut total = 0u64;
+
+        for line in self.lines.by_ref() {
+            self.line += 1;
+
+            let line = match line {
+                Ok(line) => line,
+                Err(err) => return Some(Err(err.into())),
+            };
+
+            if line.trim().is_empty() {
+                if items.is_empty() {
+                    continue;
+                }
+                return Some(Ok(self.elf(items, total)));
+            }
+
+            let calories = match line.parse::<u64>() {
+                Ok(calories) => calories,
+                Err(err) if *err.kind() == IntErrorKind::PosOverflow => {
+                    let message = format!(
+                        "elf {}'s item of {line} calories overflows u64",
+                        self.next_index
+                    );
+                    return Some(Err(aoc::Error::parse(self.line, 1, message)));
+                }
+                Err(_) => {
+                    self.malformed.push(Malformed {
+                        line: self.line,
+                        content: line,
+                    });
+                    continue;
+                }
+            };
+
+            let Some(sum) = total.checked_add(calories) else {
+                let message = format!("elf {}'s calorie total overflows u64", self.next_index);
+                return Some(Err(aoc::Error::parse(self.line, 1, message)));
+            };
+
+            total = sum;
+            items.push(calories);
+        }
+
+        (!items.is_empty()).then(|| Ok(self.elf(items, total)))
+    }
+}
+
+/// Reads every elf from `reader`, along with the malformed lines skipped in lenient mode.
+pub fn parse(reader: impl BufRead, mode: Mode) -> Result<(Vec<Elf>, Vec<Malformed>), aoc::Error> {
+    let mut elves = Elves::new(reader, mode);
+    let parsed = elves.by_ref().collect::<Result<_, _>>()?;
+    Ok((parsed, elves.finish()?))
+}
+
+/// Orders elves by total, breaking ties in favour of the one that comes first.
+struct ByTotal<E>(E);
+
+impl<E: Borrow<Elf>> ByTotal<E> {
+    fn key(&self) -> (u64, Reverse<usize>) {
+        let elf = self.0.borrow();
+        (elf.total, Reverse(elf.index))
+    }
+}
+
+impl<E: Borrow<Elf>> PartialEq for ByTotal<E> {
+    fn eq(&self, other: &Self) -> bool {
+        self.key() == other.key()
+    }
+}
+
+impl<E: Borrow<Elf>> Eq for ByTotal<E> {}
+
+impl<E: Borrow<Elf>> Ord for ByTotal<E> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.key().cmp(&other.key())
+    }
+}
+
+impl<E: Borrow<Elf>> PartialOrd for ByTotal<E> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// The `n` elves carrying the most calories, most first. Only those `n` are kept while going
+/// through `elves`.
+pub fn top<E: Borrow<Elf>>(elves: impl IntoIterator<Item = E>, n: usize) -> Vec<E> {
+    let mut heap = BinaryHeap::with_capacity(n + 1);
+
+    for elf in elves {
+        heap.push(Reverse(ByTotal(elf)));
+        if heap.len() > n {
+            heap.pop();
+        }
+    }
+
+    heap.into_sorted_vec()
+        .into_iter()
+        .map(|Reverse(ByTotal(elf))| elf)
+        .collect()
+}
+
+/// Ranked elves as returned by [`top`], printed as a table.
+pub struct Report<'a, E>(pub &'a [E]);
+
+impl<E: Borrow<Elf>> Display for Report<'_, E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{:>4}  {:>5}  {:>5}  {:>10}",
+            "rank", "elf", "items", "calories"
+        )?;
+
+        for (rank, elf) in self.0.iter().map(Borrow::borrow).enumerate() {
+            writeln!(
+                f,
+                "{:>4}  {:>5}  {:>5}  {:>10}",
+                rank + 1,
+                elf.index,
+                elf.items.len(),
+                elf.total
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn elves(input: &str) -> Vec<Elf> {
+        parse(input.as_bytes(), Mode::Strict).unwrap().0
+    }
+
+    #[test]
+    fn elves_keep_index_and_items() {
+        let elves = elves("1\n2\n\n\n3\n\n4\n5\n");
+
+        assert_eq!(elves.len(), 3);
+        assert_eq!(
+            (elves[0].index, &elves[0].items, elves[0].total()),
+            (1, &vec![1, 2], 3)
+        );
+        assert_eq!(
+            (elves[1].index, &elves[1].items, elves[1].total()),
+            (2, &vec![3], 3)
+        );
+        assert_eq!(
+            (elves[2].index, &elves[2].items, elves[2].total()),
+            (3, &vec![4, 5], 9)
+        );
+    }
+
+    #[test]
+    fn strict_mode_reports_every_malformed_line() {
+        let err = parse("1000\n12a4\n\nabc\n2000\n".as_bytes(), Mode::Strict).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            concat!(
+                "line 2, column 3: not a calorie count: \"12a4\"\n",
+                "line 4, column 1: not a calorie count: \"abc\""
+            )
+        );
+    }
+
+    #[test]
+    fn lenient_mode_skips_malformed_lines_without_splitting_elves() {
+        let (elves, malformed) = parse("1000\n12a4\n2000\n".as_bytes(), Mode::Lenient).unwrap();
+
+        assert_eq!(elves.len(), 1);
+        assert_eq!(elves[0].items, [1000, 2000]);
+        assert_eq!(
+            malformed,
+            [Malformed {
+                line: 2,
+                content: "12a4".to_string()
+            }]
+        );
+    }
+
+    #[test]
+    fn item_overflow_is_an_error_in_both_modes() {
+        for mode in [Mode::Strict, Mode::Lenient] {
+            let err = parse("1\n\n2\n99999999999999999999999\n".as_bytes(), mode).unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                "line 4, column 1: elf 2's item of 99999999999999999999999 calories overflows u64"
+            );
+        }
+    }
+
+    #[test]
+    fn whitespace_only_lines_separate_elves() {
+        assert_eq!(elves("1\n  \n2\n").len(), 2);
+    }
+
+    #[test]
+    fn top_ranks_by_total_then_position() {
+        let ranked = top(elves("5\n\n9\n\n5\n\n1\n"), 3);
+        let indices = ranked.iter().map(|elf| elf.index).collect::<Vec<_>>();
+        assert_eq!(indices, [2, 1, 3]);
+    }
+
+    #[test]
+    fn top_with_fewer_elves_than_asked() {
+        assert_eq!(top(elves("5\n\n9\n"), 10).len(), 2);
+        assert!(top(elves("5\n\n9\n"), 0).is_empty());
+    }
+
+    #[test]
+    fn report_lists_each_ranked_elf() {
+        let ranked = top(elves(include_str!("sample-input.txt")), 2);
+        assert_eq!(
+            Report(&ranked).to_string(),
+            concat!(
+                "rank    elf  items    calories\n",
+                "   1      4      3       24000\n",
+                "   2      3      2       11000\n",
+            )
+        );
+    }
+}
@@ -0,0 +1,82 @@
+use aoc::Solution;
+use elf::{Elf, Mode};
+
+pub mod elf;
+pub mod stats;
+
+/// How many of the best-stocked elves part 2 adds up.
+const TOP_ELVES: usize = 3;
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+
+    type Input = Vec<Elf>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, aoc::Error> {
+        let (elves, _) = elf::parse(input.as_bytes(), Mode::Strict)?;
+        Ok(elves)
+    }
+
+    fn part1(elves: &Self::Input) -> Result<Self::Part1, aoc::Error> {
+        Ok(elf::top(elves, 1).iter().map(|elf| elf.total()).sum())
+    }
+
+    fn part2(elves: &Self::Input) -> Result<Self::Part2, aoc::Error> {
+        Ok(elf::top(elves, TOP_ELVES)
+            .iter()
+            .map(|elf| elf.total())
+            .sum())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day1;
+    use aoc::Solution;
+    use itertools::Itertools;
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_sample_input() {
+        let elves = Day1::parse(include_str!("sample-input.txt")).unwrap();
+        assert_eq!(Day1::part1(&elves).unwrap(), 24000);
+        assert_eq!(Day1::part2(&elves).unwrap(), 45000);
+    }
+
+    #[test]
+    fn test_parse_reports_overflowing_total() {
+        let err = Day1::parse("1\n\n18446744073709551615\n1\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 4, column 1: elf 2's calorie total overflows u64"
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn parse_sums_each_group(groups in vec(vec(0..100_000u64, 1..5), 0..10)) {
+            let input = groups
+                .iter()
+                .map(|group| group.iter().join("\n"))
+                .join("\n\n");
+
+            let elves = Day1::parse(&input).unwrap();
+            let items = elves.iter().map(|elf| elf.items.clone()).collect::<Vec<_>>();
+            prop_assert_eq!(&items, &groups);
+
+            let totals = elves.iter().map(|elf| elf.total()).collect::<Vec<_>>();
+            let sums = groups.iter().map(|group| group.iter().sum()).collect::<Vec<u64>>();
+            prop_assert_eq!(totals, sums);
+        }
+
+        #[test]
+        fn parse_never_panics(input in "([0-9]{0,20}\n){0,10}|\\PC*") {
+            let _ = Day1::parse(&input);
+        }
+    }
+}
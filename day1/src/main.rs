@@ -1,19 +1,95 @@
-use itertools::Itertools;
-
-fn main() {
-    let max = include_str!("input.txt")
-        .lines()
-        .map(|v| v.parse::<u64>().ok())
-        .coalesce(|a, b| match (a, b) {
-            (None, None) => Ok(None),
-            (None, Some(b)) => Ok(Some(b)),
-            (Some(a), Some(b)) => Ok(Some(a + b)),
-            (Some(a), None) => Err((Some(a), None)),
-        })
-        .flatten()
-        .sorted_by_key(|&v| std::cmp::Reverse(v))
-        .take(3)
-        .sum::<u64>();
-
-    println!("{max:?}");
+use std::process::ExitCode;
+use std::time::Instant;
+
+use aoc::{DayArgs, InputArgs, Part, Solution};
+use clap::{Parser, Subcommand};
+use day1::elf::{self, Elves, Malformed, Mode, Report};
+use day1::stats::{Histogram, Summary};
+use day1::Day1;
+
+/// Advent of Code 2022, day 1: Calorie Counting
+#[derive(Parser)]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    #[command(flatten)]
+    args: DayArgs,
+
+    /// What to do about lines that are not calorie counts
+    #[arg(long, value_enum, default_value_t, global = true)]
+    mode: Mode,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// List the elves carrying the most calories
+    Top {
+        /// How many elves to list
+        #[arg(short, default_value_t = 3)]
+        n: usize,
+
+        #[command(flatten)]
+        input: InputArgs,
+    },
+    /// Show statistics and a histogram of the calories carried by each elf
+    Stats {
+        /// Number of histogram buckets
+        #[arg(long, default_value_t = 10)]
+        buckets: usize,
+
+        #[command(flatten)]
+        input: InputArgs,
+    },
+}
+
+fn main() -> ExitCode {
+    let cli = aoc::parse_args::<Cli>();
+
+    aoc::exit_code(match cli.command {
+        None => solve(&cli.args, cli.mode),
+        Some(Command::Top { n, input }) => top(n, &input, cli.mode),
+        Some(Command::Stats { buckets, input }) => stats(buckets, &input, cli.mode),
+    })
+}
+
+fn solve(args: &DayArgs, mode: Mode) -> Result<(), aoc::Error> {
+    if mode == Mode::Strict {
+        return args.solve::<Day1>(&Part::ALL);
+    }
+
+    let input = args.input.read(Day1::DAY)?;
+    let start = Instant::now();
+    let (elves, malformed) = elf::parse(input.as_bytes(), mode)?;
+    let parse = start.elapsed();
+    warn(&malformed);
+
+    let answers = aoc::answer::<Day1>(&elves, parse, &Part::ALL)?;
+    aoc::output::print(args.format, Day1::DAY, &input, &answers);
+    Ok(())
+}
+
+fn top(n: usize, input: &InputArgs, mode: Mode) -> Result<(), aoc::Error> {
+    let mut elves = Elves::new(input.reader(Day1::DAY)?, mode);
+    let ranked = itertools::process_results(elves.by_ref(), |elves| elf::top(elves, n))?;
+    warn(&elves.finish()?);
+
+    print!("{}", Report(&ranked));
+    Ok(())
+}
+
+fn stats(buckets: usize, input: &InputArgs, mode: Mode) -> Result<(), aoc::Error> {
+    let (elves, malformed) = elf::parse(input.reader(Day1::DAY)?, mode)?;
+    warn(&malformed);
+    let summary = Summary::of(&elves).ok_or("no elves in the input")?;
+
+    println!("{summary}");
+    print!("{}", Histogram::of(&elves, buckets));
+    Ok(())
+}
+
+fn warn(malformed: &[Malformed]) {
+    for malformed in malformed {
+        eprintln!("warning: skipped {}", aoc::Error::from(malformed));
+    }
 }
@@ -0,0 +1,259 @@
+use std::borrow::Borrow;
+use std::collections::BTreeMap;
+use std::fmt::{self, Display, Formatter};
+
+use crate::elf::Elf;
+
+/// Percentiles included in a [`Summary`].
+const PERCENTILES: [u8; 4] = [25, 75, 90, 99];
+
+/// Widest bar of a [`Histogram`], in characters.
+const BAR_WIDTH: usize = 40;
+
+/// Statistics of the calorie totals and item counts of a group of elves.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Summary {
+    pub elves: usize,
+    pub min: u64,
+    pub max: u64,
+    pub mean: f64,
+    /// The mean of the two middle totals when there is an even number of elves.
+    pub median: f64,
+    /// Nearest-rank percentiles, as `(percentile, total)`.
+    pub percentiles: Vec<(u8, u64)>,
+    /// How many elves carry each number of items.
+    pub items_per_elf: BTreeMap<usize, usize>,
+}
+
+impl Summary {
+    /// Summarizes `elves`, or `None` if there are none.
+    pub fn of<E: Borrow<Elf>>(elves: impl IntoIterator<Item = E>) -> Option<Self> {
+        let mut totals = vec![];
+        let mut items_per_elf = BTreeMap::new();
+
+        for elf in elves {
+            let elf = elf.borrow();
+            totals.push(elf.total());
+            *items_per_elf.entry(elf.items.len()).or_insert(0) += 1;
+        }
+
+        totals.sort_unstable();
+        let (&min, &max) = (totals.first()?, totals.last()?);
+
+        let count = totals.len();
+        let mid = count / 2;
+        let median = if count.is_multiple_of(2) {
+            (totals[mid - 1] as f64 + totals[mid] as f64) / 2.0
+        } else {
+            totals[mid] as f64
+        };
+
+        let percentiles = PERCENTILES
+            .iter()
+            .map(|&p| (p, percentile(&totals, p)))
+            .collect();
+
+        Some(Self {
+            elves: count,
+            min,
+            max,
+            mean: totals.iter().map(|&total| total as f64).sum::<f64>() / count as f64,
+            median,
+            percentiles,
+            items_per_elf,
+        })
+    }
+}
+
+/// The smallest total with at least `p` percent of `sorted` at or below it.
+fn percentile(sorted: &[u64], p: u8) -> u64 {
+    let rank = (sorted.len() * p as usize).div_ceil(100).max(1);
+    sorted[rank - 1]
+}
+
+impl Display for Summary {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{:<8}  {:>12}", "elves", self.elves)?;
+        writeln!(f, "{:<8}  {:>12}", "min", self.min)?;
+        writeln!(f, "{:<8}  {:>12}", "max", self.max)?;
+        writeln!(f, "{:<8}  {:>12.1}", "mean", self.mean)?;
+        writeln!(f, "{:<8}  {:>12.1}", "median", self.median)?;
+
+        for (p, total) in &self.percentiles {
+            writeln!(f, "{:<8}  {:>12}", format!("p{p}"), total)?;
+        }
+
+        writeln!(f)?;
+        writeln!(f, "{:>5}  {:>5}", "items", "elves")?;
+        for (items, elves) in &self.items_per_elf {
+            writeln!(f, "{items:>5}  {elves:>5}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A range of calorie totals and how many elves fall in it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bucket {
+    pub start: u64,
+    /// Inclusive.
+    pub end: u64,
+    pub elves: usize,
+}
+
+/// Calorie totals counted into equally wide buckets, from the lowest total to the highest.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Histogram(pub Vec<Bucket>);
+
+impl Histogram {
+    /// Splits totals into at most `buckets` ranges of equal width, rounded up so that only the
+    /// last bucket can be narrower. There are fewer buckets when the totals span fewer values.
+    pub fn of<E: Borrow<Elf>>(elves: impl IntoIterator<Item = E>, buckets: usize) -> Self {
+        let totals = elves
+            .into_iter()
+            .map(|elf| elf.borrow().total())
+            .collect::<Vec<_>>();
+
+        let (Some(&min), Some(&max)) = (totals.iter().min(), totals.iter().max()) else {
+            return Self(vec![]);
+        };
+
+        let span = max - min;
+        // Saturating only matters for a span of every u64, where it is a single bucket anyway.
+        let width = span.saturating_add(1).div_ceil(buckets.max(1) as u64);
+        let count = (span / width).min(buckets.max(1) as u64 - 1) as usize + 1;
+
+        let mut histogram = (0..count)
+            .map(|idx| Bucket {
+                start: min + idx as u64 * width,
+                end: if idx + 1 == count {
+                    max
+                } else {
+                    min + (idx as u64 + 1) * width - 1
+                },
+                elves: 0,
+            })
+            .collect::<Vec<_>>();
+
+        for total in totals {
+            let idx = (((total - min) / width) as usize).min(count - 1);
+            histogram[idx].elves += 1;
+        }
+
+        Self(histogram)
+    }
+}
+
+impl Display for Histogram {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let most = self.0.iter().map(|bucket| bucket.elves).max().unwrap_or(0);
+
+        for bucket in &self.0 {
+            let bar = (bucket.elves * BAR_WIDTH).div_ceil(most.max(1));
+            writeln!(
+                f,
+                "{:>10} - {:<10} |{:<BAR_WIDTH$}| {}",
+                bucket.start,
+                bucket.end,
+                "#".repeat(bar),
+                bucket.elves
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::elf::{self, Mode};
+
+    fn bucket(start: u64, end: u64, elves: usize) -> Bucket {
+        Bucket { start, end, elves }
+    }
+
+    /// Totals 6000, 4000, 11000, 24000 and 10000 with 3, 1, 2, 3 and 1 items.
+    fn sample() -> Vec<Elf> {
+        elf::parse(include_str!("sample-input.txt").as_bytes(), Mode::Strict)
+            .unwrap()
+            .0
+    }
+
+    #[test]
+    fn summary_of_sample() {
+        let summary = Summary::of(sample()).unwrap();
+
+        assert_eq!(summary.elves, 5);
+        assert_eq!((summary.min, summary.max), (4000, 24000));
+        assert_eq!(summary.mean, 11000.0);
+        assert_eq!(summary.median, 10000.0);
+        assert_eq!(
+            summary.percentiles,
+            [(25, 6000), (75, 11000), (90, 24000), (99, 24000)]
+        );
+        assert_eq!(
+            summary.items_per_elf,
+            BTreeMap::from([(1, 2), (2, 1), (3, 2)])
+        );
+    }
+
+    #[test]
+    fn median_of_even_count_is_mean_of_middle_totals() {
+        let (elves, _) = elf::parse("1\n\n2\n\n4\n\n10\n".as_bytes(), Mode::Strict).unwrap();
+        assert_eq!(Summary::of(&elves).unwrap().median, 3.0);
+    }
+
+    #[test]
+    fn summary_of_no_elves() {
+        assert_eq!(Summary::of(Vec::<Elf>::new()), None);
+    }
+
+    #[test]
+    fn histogram_of_sample() {
+        let histogram = Histogram::of(sample(), 4);
+
+        assert_eq!(
+            histogram.0,
+            [
+                bucket(4000, 9000, 2),
+                bucket(9001, 14001, 2),
+                bucket(14002, 19002, 0),
+                bucket(19003, 24000, 1),
+            ]
+        );
+
+        assert_eq!(
+            histogram.to_string().lines().next().unwrap(),
+            format!("{:>10} - {:<10} |{}| 2", 4000, 9000, "#".repeat(BAR_WIDTH))
+        );
+    }
+
+    #[test]
+    fn histogram_buckets_are_equally_wide_but_the_last() {
+        let (elves, _) =
+            elf::parse("1\n\n2\n\n3\n\n4\n\n5\n\n6\n\n7\n".as_bytes(), Mode::Strict).unwrap();
+
+        assert_eq!(
+            Histogram::of(&elves, 3).0,
+            [bucket(1, 3, 3), bucket(4, 6, 3), bucket(7, 7, 1)]
+        );
+        assert_eq!(
+            Histogram::of(&elves, 4).0,
+            [
+                bucket(1, 2, 2),
+                bucket(3, 4, 2),
+                bucket(5, 6, 2),
+                bucket(7, 7, 1)
+            ]
+        );
+    }
+
+    #[test]
+    fn histogram_of_equal_totals() {
+        let (elves, _) = elf::parse("5\n\n2\n3\n".as_bytes(), Mode::Strict).unwrap();
+
+        assert_eq!(Histogram::of(&elves, 10).0, [bucket(5, 5, 2)]);
+    }
+}
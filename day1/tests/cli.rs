@@ -0,0 +1,45 @@
+use std::process::{Command, Output};
+
+const SAMPLE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/sample-input.txt");
+
+fn day1(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_day1"))
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+#[test]
+fn global_option_before_subcommand() {
+    let output = day1(&["--mode", "lenient", "top", "-n", "2", SAMPLE]);
+
+    assert!(output.status.success(), "{output:?}");
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout),
+        concat!(
+            "rank    elf  items    calories\n",
+            "   1      4      3       24000\n",
+            "   2      3      2       11000\n",
+        )
+    );
+}
+
+#[test]
+fn global_option_after_subcommand() {
+    let before = day1(&["--mode", "strict", "stats", SAMPLE]);
+    let after = day1(&["stats", "--mode", "strict", SAMPLE]);
+
+    assert!(before.status.success(), "{before:?}");
+    assert_eq!(before.stdout, after.stdout);
+}
+
+#[test]
+fn input_cannot_be_combined_with_subcommand() {
+    let output = day1(&[SAMPLE, "top"]);
+
+    assert!(!output.status.success());
+    assert!(
+        String::from_utf8_lossy(&output.stderr).contains("cannot be used with 'top'"),
+        "{output:?}"
+    );
+}
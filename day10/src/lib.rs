@@ -0,0 +1,297 @@
+use std::{collections::VecDeque, str::FromStr};
+
+use aoc::{ParseError, Solution};
+use std::fmt::Write;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input = Vec<Instruction>;
+    type Part1 = i32;
+    type Part2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input, aoc::Error> {
+        parse_instructions(input)
+    }
+
+    fn part1(instructions: &Self::Input) -> Result<Self::Part1, aoc::Error> {
+        measure_signal_strength(instructions)
+    }
+
+    fn part2(instructions: &Self::Input) -> Result<Self::Part2, aoc::Error> {
+        Ok(crt_to_string(draw_crt(instructions)?))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
+    Noop,
+    Addx(i32),
+}
+
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut iter = s.split(' ');
+
+        let instruction = match iter.next() {
+            Some("noop") => Self::Noop,
+            Some("addx") => match iter.next() {
+                Some(value) => value.parse::<i32>().map(Self::Addx).map_err(|_| {
+                    ParseError::at(s, value, format!("failed to parse {value} as i32"))
+                })?,
+                None => return Err(ParseError::new("missing argument to addx")),
+            },
+            _ => return Err(ParseError::new("expected one of noop,addx")),
+        };
+
+        match iter.next() {
+            Some(extra) => Err(ParseError::at(s, extra, "unexpected argument")),
+            None => Ok(instruction),
+        }
+    }
+}
+
+/// An instruction with the cycles it has left and the 1-based line it came from.
+struct ExecutingInstruction(Instruction, u8, usize);
+
+impl ExecutingInstruction {
+    fn new(instruction: Instruction, line: usize) -> Self {
+        match instruction {
+            Instruction::Noop => Self(instruction, 1, line),
+            Instruction::Addx(_) => Self(instruction, 2, line),
+        }
+    }
+}
+
+struct Cpu {
+    queue: VecDeque<ExecutingInstruction>,
+    cycle: u32,
+    register_x: i32,
+}
+
+impl Cpu {
+    fn new(instructions: Vec<Instruction>) -> Self {
+        let queue = instructions
+            .into_iter()
+            .enumerate()
+            .map(|(idx, i)| ExecutingInstruction::new(i, idx + 1))
+            .collect();
+
+        Self {
+            queue,
+            cycle: 0,
+            register_x: 1,
+        }
+    }
+}
+
+impl Iterator for Cpu {
+    type Item = Result<(u32, i32), aoc::Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let register_x = self.register_x;
+
+        match self.queue.pop_front() {
+            None => return None,
+            Some(ExecutingInstruction(Instruction::Noop, _, _)) => {}
+            Some(ExecutingInstruction(Instruction::Addx(value), 2, line)) => {
+                self.queue
+                    .push_front(ExecutingInstruction(Instruction::Addx(value), 1, line));
+            }
+            Some(ExecutingInstruction(Instruction::Addx(value), 1, line)) => {
+                let Some(sum) = self.register_x.checked_add(value) else {
+                    self.queue.clear();
+                    return Some(Err(format!(
+                        "line {line}: addx {value} overflows register X, which is {register_x}"
+                    )
+                    .into()));
+                };
+                self.register_x = sum;
+            }
+            _ => panic!("invalid state"),
+        }
+
+        let Some(cycle) = self.cycle.checked_add(1) else {
+            self.queue.clear();
+            return Some(Err("too many cycles to count".into()));
+        };
+        self.cycle = cycle;
+
+        // return value of register x _during_ the cycle, not after it
+        Some(Ok((self.cycle, register_x)))
+    }
+}
+
+fn parse_instructions(input: &str) -> Result<Vec<Instruction>, aoc::Error> {
+    aoc::parse_lines(input, str::parse)
+}
+
+fn measure_signal_strength(instructions: &[Instruction]) -> Result<i32, aoc::Error> {
+    Cpu::new(instructions.to_vec()).try_fold(0i32, |total, step| {
+        let (cycle, x) = step?;
+        if cycle == 20 || (cycle >= 60 && (cycle - 20) % 40 == 0) {
+            i32::try_from(cycle)
+                .ok()
+                .and_then(|cycle| x.checked_mul(cycle))
+                .and_then(|increase| total.checked_add(increase))
+                .ok_or_else(|| format!("signal strength overflows i32 at cycle {cycle}").into())
+        } else {
+            Ok(total)
+        }
+    })
+}
+
+fn draw_crt(instructions: &[Instruction]) -> Result<Vec<bool>, aoc::Error> {
+    Cpu::new(instructions.to_vec())
+        .map(|step| {
+            let (cycle, x) = step?;
+            let sprite = x.saturating_sub(1)..=x.saturating_add(1);
+            let crt_pos = ((cycle - 1) % 40) as i32;
+
+            Ok(sprite.contains(&crt_pos))
+        })
+        .collect()
+}
+
+fn crt_to_string(crt: Vec<bool>) -> String {
+    let mut output = String::new();
+    for (idx, pixel) in crt.iter().enumerate() {
+        if *pixel {
+            write!(output, "#").unwrap();
+        } else {
+            write!(output, ".").unwrap();
+        }
+
+        if (idx + 1) % 40 == 0 {
+            writeln!(output).unwrap();
+        }
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn parse_noop() {
+        if let Ok(Instruction::Noop) = "noop".parse::<Instruction>() {
+            // ok
+        } else {
+            panic!("should've matched a noop instruction")
+        }
+    }
+
+    #[test]
+    fn parse_addx_ok() {
+        if let Ok(Instruction::Addx(-11)) = "addx -11".parse::<Instruction>() {
+            // ok
+        } else {
+            panic!("should've matched an addx instruction")
+        }
+    }
+
+    #[test]
+    fn parse_addx_missing_argument() {
+        match "addx".parse::<Instruction>() {
+            Err(err) => assert_eq!(err.to_string(), "missing argument to addx"),
+            _ => panic!("parse should've failed"),
+        }
+    }
+
+    #[test]
+    fn parse_unknown_command() {
+        match "unknown".parse::<Instruction>() {
+            Err(err) => assert_eq!(err.to_string(), "expected one of noop,addx"),
+            _ => panic!("parse should've failed"),
+        }
+    }
+
+    #[test]
+    fn parse_addx_bad_argument() {
+        match "addx x1".parse::<Instruction>() {
+            Err(err) => {
+                assert_eq!(err.to_string(), "failed to parse x1 as i32");
+                assert_eq!(err.column, 6);
+            }
+            _ => panic!("parse should've failed"),
+        }
+    }
+
+    #[test]
+    fn parse_instructions_reports_line() {
+        match parse_instructions("noop\nnoop 1\n") {
+            Err(err) => assert_eq!(err.to_string(), "line 2, column 6: unexpected argument"),
+            _ => panic!("parse should've failed"),
+        }
+    }
+
+    #[test]
+    fn signal_strength_for_sample_input() {
+        let signal_strength =
+            measure_signal_strength(&parse_instructions(include_str!("sample-input.txt")).unwrap())
+                .unwrap();
+        assert_eq!(signal_strength, 13140);
+    }
+
+    #[test]
+    fn print_crt_with_sample_input() {
+        let crt = draw_crt(&parse_instructions(include_str!("sample-input.txt")).unwrap()).unwrap();
+        let crt = crt_to_string(crt);
+        assert_eq!(
+            &crt,
+            concat!(
+                "##..##..##..##..##..##..##..##..##..##..\n",
+                "###...###...###...###...###...###...###.\n",
+                "####....####....####....####....####....\n",
+                "#####.....#####.....#####.....#####.....\n",
+                "######......######......######......####\n",
+                "#######.......#######.......#######.....\n"
+            )
+        )
+    }
+
+    #[test]
+    fn register_overflow_is_an_error() {
+        let instructions = parse_instructions("addx 2147483647\naddx 1\nnoop\n").unwrap();
+
+        assert_eq!(
+            measure_signal_strength(&instructions)
+                .unwrap_err()
+                .to_string(),
+            "line 1: addx 2147483647 overflows register X, which is 1"
+        );
+        assert!(draw_crt(&instructions).is_err());
+    }
+
+    #[test]
+    fn signal_strength_overflow_is_an_error() {
+        let mut input = "addx 200000000\n".to_string();
+        input.push_str(&"noop\n".repeat(20));
+
+        assert_eq!(
+            measure_signal_strength(&parse_instructions(&input).unwrap())
+                .unwrap_err()
+                .to_string(),
+            "signal strength overflows i32 at cycle 20"
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn addx_round_trips(value: i32) {
+            prop_assert_eq!(format!("addx {value}").parse(), Ok(Instruction::Addx(value)));
+        }
+
+        #[test]
+        fn parse_never_panics(input in "((noop|addx)( -?[0-9]{1,12})?\n){0,5}|\\PC*") {
+            let _ = parse_instructions(&input);
+        }
+    }
+}
@@ -0,0 +1,146 @@
+//! How the strategy guide compares with the best responses to the moves the opponent plays.
+
+use std::cmp::Reverse;
+use std::fmt::{self, Display, Formatter};
+
+use crate::rules::{Move, Rules};
+use crate::Play;
+
+/// The guide's rounds against one opponent move, next to the best response to it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Response {
+    pub theirs: Move,
+    pub rounds: usize,
+    /// Points the guide scores in these rounds.
+    pub guide: usize,
+    pub best: Move,
+    /// Points the best response would score in these rounds.
+    pub optimal: usize,
+}
+
+impl Response {
+    /// Points the guide leaves on the table.
+    pub fn gap(&self) -> usize {
+        self.optimal - self.guide
+    }
+}
+
+/// The guide's rounds grouped by the opponent's move, as returned by
+/// [`StrategyGuide::analysis`](crate::StrategyGuide::analysis).
+#[derive(Debug, Clone, PartialEq)]
+pub struct Analysis<'a> {
+    rules: &'a Rules,
+    /// One per move the opponent played, in the order of the rules.
+    pub responses: Vec<Response>,
+    /// The single move scoring the most against the opponent's observed mix of moves, and the
+    /// points it would score per round.
+    pub fixed: Option<(Move, f64)>,
+}
+
+impl<'a> Analysis<'a> {
+    pub(crate) fn new(rules: &'a Rules, rounds: &[&dyn Play]) -> Self {
+        let responses = rules
+            .moves()
+            .filter_map(|theirs| {
+                let against = rounds.iter().filter(|r| r.theirs() == theirs);
+                let count = against.clone().count();
+                let best = best_by(rules, |ours| rules.score(ours, theirs));
+
+                (count > 0).then(|| Response {
+                    theirs,
+                    rounds: count,
+                    guide: against.map(|r| r.points(rules)).sum(),
+                    best,
+                    optimal: count * rules.score(best, theirs),
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let total = rounds.len();
+        let fixed = (total > 0).then(|| {
+            let points = |ours| {
+                responses
+                    .iter()
+                    .map(|r| r.rounds * rules.score(ours, r.theirs))
+                    .sum::<usize>()
+            };
+            let best = best_by(rules, points);
+            (best, points(best) as f64 / total as f64)
+        });
+
+        Self {
+            rules,
+            responses,
+            fixed,
+        }
+    }
+
+    pub fn rounds(&self) -> usize {
+        self.responses.iter().map(|r| r.rounds).sum()
+    }
+
+    pub fn guide(&self) -> usize {
+        self.responses.iter().map(|r| r.guide).sum()
+    }
+
+    pub fn optimal(&self) -> usize {
+        self.responses.iter().map(|r| r.optimal).sum()
+    }
+}
+
+/// The move with the most points, the first of them on a tie.
+fn best_by(rules: &Rules, points: impl Fn(Move) -> usize) -> Move {
+    rules
+        .moves()
+        .min_by_key(|&ours| Reverse(points(ours)))
+        .expect("rules have moves")
+}
+
+impl Display for Analysis<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{:<8}  {:>6}  {:>6}  {:>6}  {:<8}  {:>7}  {:>6}",
+            "theirs", "rounds", "share", "guide", "best", "optimal", "gap"
+        )?;
+
+        let rounds = self.rounds();
+        for response in &self.responses {
+            let share = 100.0 * response.rounds as f64 / rounds as f64;
+            writeln!(
+                f,
+                "{:<8}  {:>6}  {:>5.1}%  {:>6}  {:<8}  {:>7}  {:>6}",
+                self.rules.name(response.theirs),
+                response.rounds,
+                share,
+                response.guide,
+                self.rules.name(response.best),
+                response.optimal,
+                response.gap()
+            )?;
+        }
+
+        writeln!(
+            f,
+            "{:<8}  {:>6}  {:>6}  {:>6}  {:<8}  {:>7}  {:>6}",
+            "total",
+            rounds,
+            "",
+            self.guide(),
+            "",
+            self.optimal(),
+            self.optimal() - self.guide()
+        )?;
+
+        if let Some((best, per_round)) = self.fixed {
+            writeln!(
+                f,
+                "best single move: {}, {per_round:.2} points per round (guide {:.2})",
+                self.rules.name(best),
+                self.guide() as f64 / rounds as f64
+            )?;
+        }
+
+        Ok(())
+    }
+}
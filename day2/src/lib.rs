@@ -0,0 +1,452 @@
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+use aoc::{ParseError, Part, Solution};
+
+pub mod analysis;
+pub mod rules;
+
+use analysis::Analysis;
+use rules::{Move, Outcome, Rules};
+
+/// A round of the strategy guide, whichever way its second column is read.
+trait Play {
+    fn theirs(&self) -> Move;
+    fn ours(&self, rules: &Rules) -> Move;
+    fn outcome(&self, rules: &Rules) -> Outcome;
+
+    fn points(&self, rules: &Rules) -> usize {
+        rules.points(self.ours(rules)) + self.outcome(rules).points()
+    }
+}
+
+/// How to read the second column of the strategy guide.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Interpretation {
+    /// X, Y and Z are the move to play (part 1)
+    Move,
+    /// X, Y and Z are the outcome to aim for (part 2)
+    Outcome,
+}
+
+impl Interpretation {
+    pub const ALL: [Interpretation; 2] = [Interpretation::Move, Interpretation::Outcome];
+
+    /// The puzzle part that reads the guide this way.
+    pub fn part(self) -> Part {
+        match self {
+            Self::Move => Part::One,
+            Self::Outcome => Part::Two,
+        }
+    }
+}
+
+impl Display for Interpretation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Move => write!(f, "second column is our move"),
+            Self::Outcome => write!(f, "second column is the outcome"),
+        }
+    }
+}
+
+fn split_round(s: &str) -> Result<(char, char), ParseError> {
+    let mut chars = s.chars();
+    let (Some(theirs), Some(' '), Some(ours), None) =
+        (chars.next(), chars.next(), chars.next(), chars.next())
+    else {
+        return Err(ParseError::new(format!(
+            "expected <theirs>SP<ours>EOF, got {s:?}"
+        )));
+    };
+
+    Ok((theirs, ours))
+}
+
+fn their_move(code: char, rules: &Rules) -> Result<Move, ParseError> {
+    rules
+        .theirs(code)
+        .ok_or_else(|| ParseError::new(format!("not a valid move: {code:?}")))
+}
+
+/// A round where the second column is the move we should play.
+#[derive(Debug, Clone, Copy)]
+pub struct MoveRound {
+    theirs: Move,
+    ours: Move,
+}
+
+impl MoveRound {
+    pub fn parse_with(s: &str, rules: &Rules) -> Result<Self, ParseError> {
+        let (theirs, ours) = split_round(s)?;
+
+        Ok(Self {
+            theirs: their_move(theirs, rules)?,
+            ours: rules.ours(ours).ok_or_else(|| {
+                ParseError::new(format!("not a valid response: {ours:?}")).at_column(3)
+            })?,
+        })
+    }
+}
+
+impl FromStr for MoveRound {
+    type Err = ParseError;
+
+    /// Parses the round with the rules of rock-paper-scissors.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_with(s, &Rules::default())
+    }
+}
+
+impl Play for MoveRound {
+    fn theirs(&self) -> Move {
+        self.theirs
+    }
+
+    fn ours(&self, _rules: &Rules) -> Move {
+        self.ours
+    }
+
+    fn outcome(&self, rules: &Rules) -> Outcome {
+        rules.outcome(self.ours, self.theirs)
+    }
+}
+
+/// A round where the second column is the outcome we should aim for.
+#[derive(Debug, Clone, Copy)]
+pub struct Round {
+    theirs: Move,
+    outcome: Outcome,
+}
+
+impl Round {
+    pub fn parse_with(s: &str, rules: &Rules) -> Result<Self, ParseError> {
+        let (theirs, outcome) = split_round(s)?;
+
+        Ok(Self {
+            theirs: their_move(theirs, rules)?,
+            outcome: Outcome::try_from(outcome).map_err(|err| err.at_column(3))?,
+        })
+    }
+}
+
+impl FromStr for Round {
+    type Err = ParseError;
+
+    /// Parses the round with the rules of rock-paper-scissors.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_with(s, &Rules::default())
+    }
+}
+
+impl Play for Round {
+    fn theirs(&self) -> Move {
+        self.theirs
+    }
+
+    fn ours(&self, rules: &Rules) -> Move {
+        rules.with_outcome(self.theirs, self.outcome)
+    }
+
+    fn outcome(&self, _rules: &Rules) -> Outcome {
+        self.outcome
+    }
+}
+
+/// The strategy guide, read under one or both interpretations of its second column.
+#[derive(Debug)]
+pub struct StrategyGuide {
+    rules: Rules,
+    by_move: Option<Vec<MoveRound>>,
+    by_outcome: Option<Vec<Round>>,
+}
+
+impl StrategyGuide {
+    /// Reads the guide only the ways in `interpretations`, so that codes another interpretation
+    /// has no meaning for are not rejected.
+    pub fn parse(
+        input: &str,
+        rules: Rules,
+        interpretations: &[Interpretation],
+    ) -> Result<Self, aoc::Error> {
+        let by_move = interpretations
+            .contains(&Interpretation::Move)
+            .then(|| aoc::parse_lines(input, |line| MoveRound::parse_with(line, &rules)))
+            .transpose()?;
+        let by_outcome = interpretations
+            .contains(&Interpretation::Outcome)
+            .then(|| aoc::parse_lines(input, |line| Round::parse_with(line, &rules)))
+            .transpose()?;
+
+        Ok(Self {
+            rules,
+            by_move,
+            by_outcome,
+        })
+    }
+
+    pub fn rules(&self) -> &Rules {
+        &self.rules
+    }
+
+    fn rounds(&self, interpretation: Interpretation) -> Result<Vec<&dyn Play>, aoc::Error> {
+        let rounds = match interpretation {
+            Interpretation::Move => self
+                .by_move
+                .as_ref()
+                .map(|rounds| rounds.iter().map(|r| r as &dyn Play).collect()),
+            Interpretation::Outcome => self
+                .by_outcome
+                .as_ref()
+                .map(|rounds| rounds.iter().map(|r| r as &dyn Play).collect()),
+        };
+
+        rounds.ok_or_else(|| {
+            format!("the guide was not read for part {}", interpretation.part()).into()
+        })
+    }
+
+    pub fn score(&self, interpretation: Interpretation) -> Result<usize, aoc::Error> {
+        let rounds = self.rounds(interpretation)?;
+        Ok(rounds.iter().map(|r| r.points(&self.rules)).sum())
+    }
+
+    /// Every round played the way `interpretation` reads the guide.
+    pub fn breakdown(&self, interpretation: Interpretation) -> Result<Breakdown<'_>, aoc::Error> {
+        Ok(Breakdown {
+            rules: &self.rules,
+            rounds: self.rounds(interpretation)?,
+        })
+    }
+
+    /// The guide, read the way `interpretation` does, against the best responses to each move
+    /// the opponent plays.
+    pub fn analysis(&self, interpretation: Interpretation) -> Result<Analysis<'_>, aoc::Error> {
+        Ok(Analysis::new(&self.rules, &self.rounds(interpretation)?))
+    }
+}
+
+/// Table of the moves, outcome and points of each round, as returned by
+/// [`StrategyGuide::breakdown`].
+pub struct Breakdown<'a> {
+    rules: &'a Rules,
+    rounds: Vec<&'a dyn Play>,
+}
+
+impl Display for Breakdown<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{:>5}  {:<8}  {:<8}  {:<7}  {:>6}",
+            "round", "theirs", "ours", "outcome", "points"
+        )?;
+
+        let rules = self.rules;
+        for (idx, round) in self.rounds.iter().enumerate() {
+            writeln!(
+                f,
+                "{:>5}  {:<8}  {:<8}  {:<7}  {:>6}",
+                idx + 1,
+                rules.name(round.theirs()),
+                rules.name(round.ours(rules)),
+                round.outcome(rules),
+                round.points(rules)
+            )?;
+        }
+
+        let total = self.rounds.iter().map(|r| r.points(rules)).sum::<usize>();
+        writeln!(f, "{:<5}  {:>35}", "total", total)
+    }
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+
+    type Input = StrategyGuide;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, aoc::Error> {
+        StrategyGuide::parse(input, Rules::default(), &Interpretation::ALL)
+    }
+
+    fn part1(guide: &Self::Input) -> Result<Self::Part1, aoc::Error> {
+        guide.score(Interpretation::Move)
+    }
+
+    fn part2(guide: &Self::Input) -> Result<Self::Part2, aoc::Error> {
+        guide.score(Interpretation::Outcome)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_sample_input() {
+        let guide = Day2::parse("A Y\nB X\nC Z\n").unwrap();
+        assert_eq!(Day2::part1(&guide).unwrap(), 15);
+        assert_eq!(Day2::part2(&guide).unwrap(), 12);
+    }
+
+    #[test]
+    fn test_breakdown_by_move() {
+        let guide = Day2::parse("A Y\nB X\nC Z\n").unwrap();
+        assert_eq!(
+            guide.breakdown(Interpretation::Move).unwrap().to_string(),
+            concat!(
+                "round  theirs    ours      outcome  points\n",
+                "    1  rock      paper     win           8\n",
+                "    2  paper     rock      loss          1\n",
+                "    3  scissors  scissors  draw          6\n",
+                "total                                   15\n",
+            )
+        );
+    }
+
+    #[test]
+    fn test_breakdown_by_outcome() {
+        let guide = Day2::parse("A Y\nB X\nC Z\n").unwrap();
+        assert_eq!(
+            guide
+                .breakdown(Interpretation::Outcome)
+                .unwrap()
+                .to_string(),
+            concat!(
+                "round  theirs    ours      outcome  points\n",
+                "    1  rock      rock      draw          4\n",
+                "    2  paper     rock      loss          1\n",
+                "    3  scissors  rock      win           7\n",
+                "total                                   12\n",
+            )
+        );
+    }
+
+    #[test]
+    fn test_analysis_of_sample_input() {
+        let guide = Day2::parse("A Y\nB X\nC Z\n").unwrap();
+        let analysis = guide.analysis(Interpretation::Outcome).unwrap();
+
+        assert_eq!((analysis.guide(), analysis.optimal()), (12, 24));
+        assert_eq!(
+            analysis.to_string(),
+            concat!(
+                "theirs    rounds   share   guide  best      optimal     gap\n",
+                "rock           1   33.3%       4  paper           8       4\n",
+                "paper          1   33.3%       1  scissors        9       8\n",
+                "scissors       1   33.3%       7  rock            7       0\n",
+                "total          3              12                 24      12\n",
+                "best single move: scissors, 6.00 points per round (guide 4.00)\n",
+            )
+        );
+    }
+
+    #[test]
+    fn test_analysis_groups_rounds_by_opponent_move() {
+        let guide = Day2::parse("A X\nA Y\nA Z\nC Y\n").unwrap();
+        let analysis = guide.analysis(Interpretation::Move).unwrap();
+
+        let rules = guide.rules();
+        let rounds = analysis
+            .responses
+            .iter()
+            .map(|r| (rules.name(r.theirs), r.rounds, r.guide, r.optimal))
+            .collect::<Vec<_>>();
+        assert_eq!(rounds, [("rock", 3, 4 + 8 + 3, 24), ("scissors", 1, 2, 7)]);
+
+        let (best, per_round) = analysis.fixed.unwrap();
+        assert_eq!((rules.name(best), per_round), ("paper", 6.5));
+    }
+
+    #[test]
+    fn test_analysis_of_empty_guide() {
+        let guide = Day2::parse("").unwrap();
+        let analysis = guide.analysis(Interpretation::Move).unwrap();
+
+        assert!(analysis.responses.is_empty());
+        assert_eq!(analysis.fixed, None);
+    }
+
+    #[test]
+    fn test_breakdown_with_rpsls_rules() {
+        let rules = Rules::from_json(include_str!("../rpsls.json")).unwrap();
+        let guide = StrategyGuide::parse("A Z\nD X\nE Y\n", rules, &Interpretation::ALL).unwrap();
+
+        assert_eq!(guide.score(Interpretation::Move).unwrap(), 11 + 9 + 10);
+        assert_eq!(
+            guide
+                .breakdown(Interpretation::Outcome)
+                .unwrap()
+                .to_string(),
+            concat!(
+                "round  theirs    ours      outcome  points\n",
+                "    1  rock      spock     win          11\n",
+                "    2  lizard    spock     loss          5\n",
+                "    3  spock     spock     draw          8\n",
+                "total                                   24\n",
+            )
+        );
+    }
+
+    #[test]
+    fn test_codes_outside_the_rules() {
+        let rules = Rules::from_json(include_str!("../rpsls.json")).unwrap();
+
+        let err =
+            StrategyGuide::parse("A X\nF X\n", rules.clone(), &Interpretation::ALL).unwrap_err();
+        assert_eq!(err.to_string(), "line 2, column 1: not a valid move: 'F'");
+
+        let err = StrategyGuide::parse("A U\n", rules, &[Interpretation::Move]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 3: not a valid response: 'U'"
+        );
+    }
+
+    #[test]
+    fn test_rpsls_codes_only_our_moves_have() {
+        let rules = Rules::from_json(include_str!("../rpsls.json")).unwrap();
+        let input = "A V\nB W\nC V\n";
+
+        let guide = StrategyGuide::parse(input, rules.clone(), &[Interpretation::Move]).unwrap();
+        assert_eq!(guide.score(Interpretation::Move).unwrap(), 4 + 5 + 7);
+        assert_eq!(
+            guide
+                .score(Interpretation::Outcome)
+                .unwrap_err()
+                .to_string(),
+            "the guide was not read for part 2"
+        );
+
+        let err = StrategyGuide::parse(input, rules, &Interpretation::ALL).unwrap_err();
+        assert_eq!(err.to_string(), "line 1, column 3: not a valid outcome: V");
+    }
+
+    proptest! {
+        #[test]
+        fn round_round_trips(theirs in 0..3usize, outcome in 0..3usize) {
+            let line = format!("{} {}", b"ABC"[theirs] as char, b"XYZ"[outcome] as char);
+
+            let moves = Rules::default().moves().collect::<Vec<_>>();
+
+            let round = Round::from_str(&line).unwrap();
+            prop_assert_eq!(round.theirs, moves[theirs]);
+            prop_assert_eq!(round.outcome, Outcome::ALL[outcome]);
+
+            let move_round = MoveRound::from_str(&line).unwrap();
+            prop_assert_eq!(move_round.theirs, moves[theirs]);
+            prop_assert_eq!(move_round.ours, moves[outcome]);
+        }
+
+        #[test]
+        fn round_rejects_anything_else(line in "\\PC{0,5}") {
+            let valid = matches!(line.as_bytes(), [b'A'..=b'C', b' ', b'X'..=b'Z']);
+            prop_assert_eq!(Round::from_str(&line).is_ok(), valid);
+            prop_assert_eq!(MoveRound::from_str(&line).is_ok(), valid);
+        }
+    }
+}
@@ -1,126 +1,104 @@
-use std::str::FromStr;
-
-use color_eyre::{eyre::eyre, Report};
-use itertools::Itertools;
-
-trait Points {
-    fn points(&self) -> usize;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::Instant;
+
+use aoc::{DayArgs, InputArgs, Solution};
+use clap::{Parser, Subcommand};
+use day2::rules::Rules;
+use day2::{Day2, Interpretation, StrategyGuide};
+
+/// Advent of Code 2022, day 2: Rock Paper Scissors
+#[derive(Parser)]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    #[command(flatten)]
+    args: DayArgs,
+
+    /// Only read the guide this way; both ways are used when omitted
+    #[arg(long, value_enum, global = true)]
+    interpretation: Option<Interpretation>,
+
+    /// JSON file with the moves of the game and which beats which; rock-paper-scissors when
+    /// omitted
+    #[arg(long, value_name = "FILE", global = true)]
+    rules: Option<PathBuf>,
 }
 
-#[derive(Debug, Clone, Copy)]
-enum Move {
-    Rock,
-    Paper,
-    Scissors,
+#[derive(Subcommand)]
+enum Command {
+    /// Show the moves, outcome and points of every round
+    Breakdown {
+        #[command(flatten)]
+        input: InputArgs,
+    },
+    /// Compare the guide with the best response to each move the opponent plays
+    Analyze {
+        #[command(flatten)]
+        input: InputArgs,
+    },
 }
 
-impl TryFrom<char> for Move {
-    type Error = Report;
-
-    fn try_from(value: char) -> Result<Self, Self::Error> {
-        match value {
-            'A' => Ok(Move::Rock),
-            'B' => Ok(Move::Paper),
-            'C' => Ok(Move::Scissors),
-            _ => Err(eyre!("not a valid move: {value:?}")),
+fn main() -> ExitCode {
+    let cli = aoc::parse_args::<Cli>();
+    let interpretations = match cli.interpretation {
+        Some(interpretation) => vec![interpretation],
+        None => Interpretation::ALL.to_vec(),
+    };
+
+    let parts = interpretations.iter().map(|i| i.part()).collect::<Vec<_>>();
+
+    aoc::exit_code(match (cli.command, cli.rules) {
+        (None, None) => cli.args.solve::<Day2>(&parts),
+        (None, Some(rules)) => solve_with(&cli.args, &rules, &interpretations),
+        (Some(Command::Breakdown { input }), rules) => {
+            report(&input, rules.as_deref(), &interpretations, |guide, i| {
+                Ok(guide.breakdown(i)?.to_string())
+            })
         }
-    }
-}
-
-impl Points for Move {
-    fn points(&self) -> usize {
-        match self {
-            Move::Rock => 1,
-            Move::Paper => 2,
-            Move::Scissors => 3,
+        (Some(Command::Analyze { input }), rules) => {
+            report(&input, rules.as_deref(), &interpretations, |guide, i| {
+                Ok(guide.analysis(i)?.to_string())
+            })
         }
-    }
+    })
 }
 
-impl Move {
-    fn with_outcome(self, outcome: Outcome) -> Self {
-        match (self, outcome) {
-            (_, Outcome::Draw) => self,
-            (Self::Rock, Outcome::Loss) => Self::Scissors,
-            (Self::Rock, Outcome::Win) => Self::Paper,
-            (Self::Paper, Outcome::Loss) => Self::Rock,
-            (Self::Paper, Outcome::Win) => Self::Scissors,
-            (Self::Scissors, Outcome::Loss) => Self::Paper,
-            (Self::Scissors, Outcome::Win) => Self::Rock,
-        }
-    }
-}
+fn solve_with(
+    args: &DayArgs,
+    rules: &Path,
+    interpretations: &[Interpretation],
+) -> Result<(), aoc::Error> {
+    let rules = Rules::load(rules)?;
+    let input = args.input.read(Day2::DAY)?;
+    let parts = interpretations.iter().map(|i| i.part()).collect::<Vec<_>>();
 
-#[derive(Debug, Clone, Copy)]
-enum Outcome {
-    Loss,
-    Draw,
-    Win,
-}
+    let start = Instant::now();
+    let guide = StrategyGuide::parse(&input, rules, interpretations)?;
+    let answers = aoc::answer::<Day2>(&guide, start.elapsed(), &parts)?;
 
-impl Points for Outcome {
-    fn points(&self) -> usize {
-        match self {
-            Self::Loss => 0,
-            Self::Draw => 3,
-            Self::Win => 6,
-        }
-    }
+    aoc::output::print(args.format, Day2::DAY, &input, &answers);
+    Ok(())
 }
 
-impl TryFrom<char> for Outcome {
-    type Error = Report;
-
-    fn try_from(c: char) -> Result<Self, Report> {
-        match c {
-            'X' => Ok(Self::Loss),
-            'Y' => Ok(Self::Draw),
-            'Z' => Ok(Self::Win),
-            _ => Err(eyre!("not a valid outcome: {c}")),
+/// Prints a table about the guide for each interpretation.
+fn report(
+    input: &InputArgs,
+    rules: Option<&Path>,
+    interpretations: &[Interpretation],
+    table: impl Fn(&StrategyGuide, Interpretation) -> Result<String, aoc::Error>,
+) -> Result<(), aoc::Error> {
+    let rules = rules.map_or_else(|| Ok(Rules::default()), Rules::load)?;
+    let guide = StrategyGuide::parse(&input.read(Day2::DAY)?, rules, interpretations)?;
+
+    for (idx, &interpretation) in interpretations.iter().enumerate() {
+        if idx > 0 {
+            println!();
         }
+        println!("{interpretation}:");
+        print!("{}", table(&guide, interpretation)?);
     }
-}
-
-#[derive(Debug, Clone, Copy)]
-struct Round {
-    theirs: Move,
-    outcome: Outcome,
-}
-
-impl FromStr for Round {
-    type Err = Report;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut chars = s.chars();
-        let (Some(theirs), Some(' '), Some(outcome), None) = (chars.next(), chars.next(), chars.next(), chars.next()) else {
-            return Err(eyre!("expected <theirs>SP<ours>EOF, got {s:?}"));
-        };
-
-        Ok(Self {
-            theirs: theirs.try_into()?,
-            outcome: outcome.try_into()?,
-        })
-    }
-}
-
-impl Points for Round {
-    fn points(&self) -> usize {
-        let ours = self.theirs.with_outcome(self.outcome);
-        ours.points() + self.outcome.points()
-    }
-}
-
-fn main() -> Result<(), Report> {
-    color_eyre::install()?;
-
-    let total_score: usize = itertools::process_results(
-        include_str!("input.txt")
-            .lines()
-            .map(Round::from_str)
-            .map_ok(|r| r.points()),
-        |it| it.sum(),
-    )?;
 
-    dbg!(total_score);
     Ok(())
 }
@@ -0,0 +1,317 @@
+//! The moves of a cyclic game like rock-paper-scissors and which of them beats which.
+//!
+//! Rules other than the default rock-paper-scissors are read from JSON. Each move has the code
+//! the opponent's column uses for it, the code the second column uses when it is read as our
+//! move, and the points for playing it:
+//!
+//! ```json
+//! {
+//!   "moves": [
+//!     { "name": "rock", "theirs": "A", "ours": "X", "points": 1 },
+//!     { "name": "paper", "theirs": "B", "ours": "Y", "points": 2 },
+//!     { "name": "scissors", "theirs": "C", "ours": "Z", "points": 3 }
+//!   ],
+//!   "beats": [["rock", "scissors"], ["paper", "rock"], ["scissors", "paper"]]
+//! }
+//! ```
+//!
+//! Every two distinct moves must be settled by exactly one `beats` pair, and every move must
+//! both beat and be beaten by some other move, so that any outcome can be aimed for.
+
+use std::fmt::{self, Display, Formatter};
+use std::path::Path;
+
+use aoc::ParseError;
+use serde::Deserialize;
+
+/// One of the moves of a [`Rules`], by position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Move(usize);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Outcome {
+    Loss,
+    Draw,
+    Win,
+}
+
+impl Outcome {
+    pub const ALL: [Outcome; 3] = [Outcome::Loss, Outcome::Draw, Outcome::Win];
+
+    pub fn points(self) -> usize {
+        match self {
+            Self::Loss => 0,
+            Self::Draw => 3,
+            Self::Win => 6,
+        }
+    }
+}
+
+impl TryFrom<char> for Outcome {
+    type Error = ParseError;
+
+    fn try_from(c: char) -> Result<Self, ParseError> {
+        match c {
+            'X' => Ok(Self::Loss),
+            'Y' => Ok(Self::Draw),
+            'Z' => Ok(Self::Win),
+            _ => Err(ParseError::new(format!("not a valid outcome: {c}"))),
+        }
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.pad(match self {
+            Self::Loss => "loss",
+            Self::Draw => "draw",
+            Self::Win => "win",
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct MoveRule {
+    pub name: String,
+    /// Code of the move in the opponent's column.
+    pub theirs: char,
+    /// Code of the move in the second column, when it is read as our move.
+    pub ours: char,
+    pub points: usize,
+}
+
+#[derive(Deserialize)]
+struct RulesFile {
+    moves: Vec<MoveRule>,
+    beats: Vec<(String, String)>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rules {
+    moves: Vec<MoveRule>,
+    /// `beats[a][b]` is whether move `a` beats move `b`.
+    beats: Vec<Vec<bool>>,
+}
+
+impl Rules {
+    /// Checks that `beats`, given as `(winner, loser)` names, settles every pair of `moves`.
+    pub fn new(moves: Vec<MoveRule>, beats: &[(String, String)]) -> Result<Self, aoc::Error> {
+        for (idx, rule) in moves.iter().enumerate() {
+            for other in &moves[..idx] {
+                if other.name == rule.name || other.theirs == rule.theirs || other.ours == rule.ours
+                {
+                    return Err(format!("moves {:?} and {:?} clash", other.name, rule.name).into());
+                }
+            }
+        }
+
+        let index = |name: &str| {
+            moves
+                .iter()
+                .position(|rule| rule.name == name)
+                .ok_or_else(|| format!("unknown move {name:?}"))
+        };
+
+        let mut table = vec![vec![false; moves.len()]; moves.len()];
+        for (winner, loser) in beats {
+            let (w, l) = (index(winner)?, index(loser)?);
+            if w == l || table[l][w] {
+                return Err(format!("{winner:?} and {loser:?} cannot beat each other").into());
+            }
+            table[w][l] = true;
+        }
+
+        let rules = Self {
+            moves,
+            beats: table,
+        };
+
+        for a in rules.moves() {
+            for b in rules.moves().filter(|&b| b > a) {
+                if !rules.beats(a, b) && !rules.beats(b, a) {
+                    let (a, b) = (rules.name(a), rules.name(b));
+                    return Err(format!("neither of {a:?} and {b:?} beats the other").into());
+                }
+            }
+
+            let beats_any = rules.moves().any(|b| rules.beats(a, b));
+            let beaten = rules.moves().any(|b| rules.beats(b, a));
+            if !beats_any || !beaten {
+                let name = rules.name(a);
+                return Err(format!("{name:?} must both beat and be beaten by some move").into());
+            }
+        }
+
+        Ok(rules)
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, aoc::Error> {
+        let file: RulesFile =
+            serde_json::from_str(json).map_err(|err| format!("invalid rules: {err}"))?;
+        Self::new(file.moves, &file.beats)
+    }
+
+    pub fn load(path: &Path) -> Result<Self, aoc::Error> {
+        let json = std::fs::read_to_string(path)
+            .map_err(|err| format!("failed to read {}: {err}", path.display()))?;
+        Self::from_json(&json).map_err(|err| format!("{}: {err}", path.display()).into())
+    }
+
+    pub fn moves(&self) -> impl Iterator<Item = Move> {
+        (0..self.moves.len()).map(Move)
+    }
+
+    pub fn name(&self, m: Move) -> &str {
+        &self.moves[m.0].name
+    }
+
+    pub fn points(&self, m: Move) -> usize {
+        self.moves[m.0].points
+    }
+
+    /// The move the opponent's column calls `code`.
+    pub fn theirs(&self, code: char) -> Option<Move> {
+        self.moves
+            .iter()
+            .position(|rule| rule.theirs == code)
+            .map(Move)
+    }
+
+    /// The move the second column calls `code`, when it is read as our move.
+    pub fn ours(&self, code: char) -> Option<Move> {
+        self.moves
+            .iter()
+            .position(|rule| rule.ours == code)
+            .map(Move)
+    }
+
+    pub fn beats(&self, a: Move, b: Move) -> bool {
+        self.beats[a.0][b.0]
+    }
+
+    /// How playing `ours` against `theirs` ends for us.
+    pub fn outcome(&self, ours: Move, theirs: Move) -> Outcome {
+        if self.beats(ours, theirs) {
+            Outcome::Win
+        } else if self.beats(theirs, ours) {
+            Outcome::Loss
+        } else {
+            Outcome::Draw
+        }
+    }
+
+    /// The points for playing `ours` against `theirs`.
+    pub fn score(&self, ours: Move, theirs: Move) -> usize {
+        self.points(ours) + self.outcome(ours, theirs).points()
+    }
+
+    /// The move that ends in `outcome` against `theirs`. When several do, the one worth the most
+    /// points is picked, and the first of those on a tie.
+    pub fn with_outcome(&self, theirs: Move, outcome: Outcome) -> Move {
+        self.moves()
+            .filter(|&ours| self.outcome(ours, theirs) == outcome)
+            .min_by_key(|&ours| std::cmp::Reverse(self.points(ours)))
+            .expect("rules are validated so that every outcome can be reached")
+    }
+}
+
+impl Default for Rules {
+    /// Rock, paper and scissors.
+    fn default() -> Self {
+        let rule = |name: &str, theirs, ours, points| MoveRule {
+            name: name.to_owned(),
+            theirs,
+            ours,
+            points,
+        };
+
+        let moves = vec![
+            rule("rock", 'A', 'X', 1),
+            rule("paper", 'B', 'Y', 2),
+            rule("scissors", 'C', 'Z', 3),
+        ];
+
+        let beats = [
+            ("rock", "scissors"),
+            ("paper", "rock"),
+            ("scissors", "paper"),
+        ]
+        .map(|(winner, loser)| (winner.to_owned(), loser.to_owned()));
+
+        Self::new(moves, &beats).expect("rock-paper-scissors rules are valid")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RPSLS: &str = include_str!("../rpsls.json");
+
+    fn named(rules: &Rules, name: &str) -> Move {
+        rules.moves().find(|&m| rules.name(m) == name).unwrap()
+    }
+
+    #[test]
+    fn default_rules_are_rock_paper_scissors() {
+        let rules = Rules::default();
+        let (rock, paper, scissors) = (
+            named(&rules, "rock"),
+            named(&rules, "paper"),
+            named(&rules, "scissors"),
+        );
+
+        assert_eq!(rules.outcome(paper, rock), Outcome::Win);
+        assert_eq!(rules.outcome(rock, paper), Outcome::Loss);
+        assert_eq!(rules.outcome(scissors, scissors), Outcome::Draw);
+        assert_eq!(rules.with_outcome(rock, Outcome::Loss), scissors);
+        assert_eq!(rules.with_outcome(rock, Outcome::Win), paper);
+        assert_eq!(rules.theirs('B'), Some(paper));
+        assert_eq!(rules.ours('Z'), Some(scissors));
+    }
+
+    #[test]
+    fn rpsls_picks_the_best_scoring_winning_move() {
+        let rules = Rules::from_json(RPSLS).unwrap();
+
+        // both paper (2) and spock (5) beat rock
+        assert_eq!(
+            rules.with_outcome(named(&rules, "rock"), Outcome::Win),
+            named(&rules, "spock")
+        );
+        assert_eq!(
+            rules.outcome(named(&rules, "lizard"), named(&rules, "spock")),
+            Outcome::Win
+        );
+    }
+
+    #[test]
+    fn rules_must_settle_every_pair() {
+        let json = RPSLS.replace(r#"["rock", "scissors"]"#, r#"["scissors", "scissors"]"#);
+        assert_eq!(
+            Rules::from_json(&json).unwrap_err().to_string(),
+            r#""scissors" and "scissors" cannot beat each other"#
+        );
+
+        let json = RPSLS.replace(r#", ["rock", "scissors"]"#, "");
+        assert_eq!(
+            Rules::from_json(&json).unwrap_err().to_string(),
+            r#"neither of "rock" and "scissors" beats the other"#
+        );
+    }
+
+    #[test]
+    fn rules_reject_unknown_and_clashing_moves() {
+        let json = RPSLS.replace(r#"["paper", "rock"]"#, r#"["paper", "stone"]"#);
+        assert_eq!(
+            Rules::from_json(&json).unwrap_err().to_string(),
+            r#"unknown move "stone""#
+        );
+
+        let json = RPSLS.replace(r#""ours": "Z""#, r#""ours": "V""#);
+        assert_eq!(
+            Rules::from_json(&json).unwrap_err().to_string(),
+            r#"moves "rock" and "spock" clash"#
+        );
+    }
+}
@@ -0,0 +1,57 @@
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+const SAMPLE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/sample-input.txt");
+const RPSLS: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/rpsls.json");
+
+fn day2(args: &[&str], stdin: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_day2"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(stdin.as_bytes())
+        .unwrap();
+    child.wait_with_output().unwrap()
+}
+
+#[test]
+fn global_options_before_subcommand() {
+    let output = day2(&["--interpretation", "move", "breakdown", SAMPLE], "");
+
+    assert!(output.status.success(), "{output:?}");
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout),
+        concat!(
+            "second column is our move:\n",
+            "round  theirs    ours      outcome  points\n",
+            "    1  rock      paper     win           8\n",
+            "    2  paper     rock      loss          1\n",
+            "    3  scissors  scissors  draw          6\n",
+            "total                                   15\n",
+        )
+    );
+}
+
+#[test]
+fn rules_with_their_own_codes_for_our_moves() {
+    let args = ["--rules", RPSLS, "--interpretation", "move", "-"];
+    let output = day2(&args, "A V\nB W\n");
+
+    assert!(output.status.success(), "{output:?}");
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "day 2 part 1: 9\n");
+
+    let output = day2(&["--rules", RPSLS, "analyze", "-"], "A V\nB W\n");
+    assert!(!output.status.success());
+    assert_eq!(
+        String::from_utf8_lossy(&output.stderr),
+        "error: line 1, column 3: not a valid outcome: V\n"
+    );
+}
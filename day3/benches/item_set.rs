@@ -0,0 +1,123 @@
+//! Compares `ItemSet` with the `im::HashSet` it replaced, on generated inputs much larger than
+//! the puzzle's. Run with `cargo bench -p day3`.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use aoc::bench::Stats;
+use aoc::Solution;
+use day3::item::{Item, ItemSet};
+use day3::rucksack::Rucksack;
+use day3::Day3;
+use im::HashSet;
+
+const RUCKSACKS: usize = 100_000;
+const RUNS: usize = 10;
+
+/// Rucksacks of 10 to 60 random letters from a fixed-seed generator, so runs are comparable.
+fn generate(rucksacks: usize) -> String {
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+    let mut next = move || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state
+    };
+
+    let mut input = String::new();
+    for _ in 0..rucksacks {
+        let len = 2 * (5 + next() % 26);
+        for _ in 0..len {
+            let letter = next() % 52;
+            input.push(if letter < 26 {
+                (b'a' + letter as u8) as char
+            } else {
+                (b'A' + (letter - 26) as u8) as char
+            });
+        }
+        input.push('\n');
+    }
+    input
+}
+
+/// Sums the priorities of every item in both compartments and every item common to a group.
+fn with_item_set(rucksacks: &[Rucksack]) -> usize {
+    let sets = rucksacks
+        .iter()
+        .map(|rucksack| {
+            let (left, right) = rucksack.compartments();
+            let left = left.iter().copied().collect::<ItemSet>();
+            let right = right.iter().copied().collect::<ItemSet>();
+            (left.union(right), left.intersection(right))
+        })
+        .collect::<Vec<_>>();
+
+    let duplicates = sets.iter().flat_map(|(_, both)| both.iter());
+    let badges = sets.chunks(3).flat_map(|group| {
+        group
+            .iter()
+            .map(|(all, _)| *all)
+            .reduce(ItemSet::intersection)
+            .unwrap_or_default()
+            .iter()
+    });
+
+    duplicates.chain(badges).map(Item::priority).sum()
+}
+
+/// [`with_item_set`], the way day 3 used to do it.
+fn with_im(rucksacks: &[Rucksack]) -> usize {
+    let sets = rucksacks
+        .iter()
+        .map(|rucksack| {
+            let (left, right) = rucksack.compartments();
+            let left = left.iter().copied().collect::<HashSet<Item>>();
+            let right = right.iter().copied().collect::<HashSet<Item>>();
+            (left.clone().union(right.clone()), left.intersection(right))
+        })
+        .collect::<Vec<_>>();
+
+    let duplicates = sets.iter().flat_map(|(_, both)| both.iter().copied());
+    let badges = sets.chunks(3).flat_map(|group| {
+        group
+            .iter()
+            .map(|(all, _)| all.clone())
+            .reduce(|a, b| a.intersection(b))
+            .unwrap_or_default()
+            .into_iter()
+    });
+
+    duplicates.chain(badges).map(Item::priority).sum()
+}
+
+fn bench(name: &str, rucksacks: &[Rucksack], f: fn(&[Rucksack]) -> usize) -> usize {
+    let mut answer = 0;
+    let samples = (0..RUNS)
+        .map(|_| {
+            let start = Instant::now();
+            answer = black_box(f(black_box(rucksacks)));
+            start.elapsed()
+        })
+        .collect::<Vec<Duration>>();
+
+    let stats = Stats::of(&samples);
+    println!(
+        "{:<8}  {:>12.2?}  {:>12.2?}  {:>12.2?}",
+        name, stats.min, stats.median, stats.max
+    );
+    answer
+}
+
+fn main() {
+    let rucksacks = Day3::parse(&generate(RUCKSACKS)).unwrap();
+
+    println!("{RUCKSACKS} rucksacks, {RUNS} runs");
+    println!(
+        "{:<8}  {:>12}  {:>12}  {:>12}",
+        "sets", "min", "median", "max"
+    );
+
+    let bits = bench("ItemSet", &rucksacks, with_item_set);
+    let im = bench("im", &rucksacks, with_im);
+    assert_eq!(bits, im, "both kinds of set must find the same items");
+}
@@ -0,0 +1,201 @@
+//! Groups of elves and the badge their rucksacks have in common.
+
+use std::fmt::{self, Display, Formatter};
+
+use crate::item::{Item, ItemSet};
+use crate::rucksack::Rucksack;
+
+/// Elves per group in the puzzle.
+pub const GROUP_SIZE: usize = 3;
+
+/// The rucksacks of one group of elves, on consecutive lines.
+#[derive(Debug, Clone, Copy)]
+pub struct Group<'a> {
+    pub first_line: usize,
+    pub rucksacks: &'a [Rucksack],
+}
+
+impl Group<'_> {
+    pub fn last_line(&self) -> usize {
+        self.first_line + self.rucksacks.len() - 1
+    }
+
+    /// The items in every rucksack of the group.
+    pub fn common(&self) -> ItemSet {
+        self.rucksacks
+            .iter()
+            .map(|rucksack| rucksack.items().iter().copied().collect::<ItemSet>())
+            .reduce(ItemSet::intersection)
+            .unwrap_or_default()
+    }
+}
+
+/// Splits `rucksacks` into groups of `size`, which must not be 0. The last group is smaller when
+/// there are not enough rucksacks left to fill it.
+pub fn groups(rucksacks: &[Rucksack], size: usize) -> impl Iterator<Item = Group<'_>> {
+    rucksacks
+        .chunks(size)
+        .enumerate()
+        .map(move |(idx, rucksacks)| Group {
+            first_line: idx * size + 1,
+            rucksacks,
+        })
+}
+
+/// Why a group has no badge.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Problem {
+    /// The last group is short of rucksacks.
+    Incomplete {
+        first_line: usize,
+        last_line: usize,
+        size: usize,
+    },
+    NoCommonItem {
+        first_line: usize,
+        last_line: usize,
+    },
+    Ambiguous {
+        first_line: usize,
+        last_line: usize,
+        items: ItemSet,
+    },
+}
+
+impl Display for Problem {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Incomplete {
+                first_line,
+                last_line,
+                size,
+            } => write!(
+                f,
+                "lines {first_line}-{last_line}: the last group has {} of {size} rucksacks",
+                last_line - first_line + 1
+            ),
+            Self::NoCommonItem {
+                first_line,
+                last_line,
+            } => write!(
+                f,
+                "lines {first_line}-{last_line}: no item is in every rucksack"
+            ),
+            Self::Ambiguous {
+                first_line,
+                last_line,
+                items,
+            } => write!(
+                f,
+                "lines {first_line}-{last_line}: items {items:?} are all in every rucksack"
+            ),
+        }
+    }
+}
+
+/// The badge of each group of `size`, or every group that does not have exactly one.
+pub fn badges(rucksacks: &[Rucksack], size: usize) -> Result<Vec<Item>, Vec<Problem>> {
+    let mut badges = vec![];
+    let mut problems = vec![];
+
+    for group in groups(rucksacks, size) {
+        let (first_line, last_line) = (group.first_line, group.last_line());
+
+        if group.rucksacks.len() < size {
+            problems.push(Problem::Incomplete {
+                first_line,
+                last_line,
+                size,
+            });
+            continue;
+        }
+
+        let common = group.common();
+        match common.iter().collect::<Vec<_>>()[..] {
+            [badge] => badges.push(badge),
+            [] => problems.push(Problem::NoCommonItem {
+                first_line,
+                last_line,
+            }),
+            _ => problems.push(Problem::Ambiguous {
+                first_line,
+                last_line,
+                items: common,
+            }),
+        }
+    }
+
+    if problems.is_empty() {
+        Ok(badges)
+    } else {
+        Err(problems)
+    }
+}
+
+/// The sum of the priorities of the badges of the groups of `size`.
+pub fn badge_sum(rucksacks: &[Rucksack], size: usize) -> Result<usize, aoc::Error> {
+    match badges(rucksacks, size) {
+        Ok(badges) => Ok(badges.into_iter().map(Item::priority).sum()),
+        Err(problems) => Err(problems
+            .iter()
+            .map(Problem::to_string)
+            .collect::<Vec<_>>()
+            .join("\n")
+            .into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day3;
+    use aoc::Solution;
+
+    #[test]
+    fn badges_of_sample_input() {
+        let rucksacks = Day3::parse(include_str!("sample-input.txt")).unwrap();
+        let badges = badges(&rucksacks, GROUP_SIZE).unwrap();
+        assert_eq!(format!("{badges:?}"), "[r, Z]");
+    }
+
+    #[test]
+    fn group_size_is_configurable() {
+        let rucksacks = Day3::parse("abca\nxbyb\nbq\nbr\n").unwrap();
+        assert_eq!(badge_sum(&rucksacks, 2).unwrap(), 2 + 2);
+        assert_eq!(badge_sum(&rucksacks, 4).unwrap(), 2);
+    }
+
+    #[test]
+    fn every_problem_is_reported_with_its_lines() {
+        let rucksacks = Day3::parse("ab\nac\nad\nab\nab\nab\nzz\nyy\nzy\nab\n").unwrap();
+
+        assert_eq!(
+            badges(&rucksacks, 3).unwrap_err(),
+            [
+                Problem::Ambiguous {
+                    first_line: 4,
+                    last_line: 6,
+                    items: "ab".bytes().map(|b| Item::try_from(b).unwrap()).collect(),
+                },
+                Problem::NoCommonItem {
+                    first_line: 7,
+                    last_line: 9
+                },
+                Problem::Incomplete {
+                    first_line: 10,
+                    last_line: 10,
+                    size: 3
+                },
+            ]
+        );
+
+        assert_eq!(
+            badge_sum(&rucksacks, 3).unwrap_err().to_string(),
+            concat!(
+                "lines 4-6: items {a, b} are all in every rucksack\n",
+                "lines 7-9: no item is in every rucksack\n",
+                "lines 10-10: the last group has 1 of 3 rucksacks",
+            )
+        );
+    }
+}
@@ -0,0 +1,242 @@
+use aoc::Solution;
+use group::GROUP_SIZE;
+use item::Item;
+use rucksack::Rucksack;
+
+pub mod group;
+pub mod rucksack;
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+
+    type Input = Vec<Rucksack>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, aoc::Error> {
+        aoc::parse_lines(input, |line| {
+            line.bytes()
+                .enumerate()
+                .map(|(idx, b)| Item::try_from(b).map_err(|err| err.at_column(idx + 1)))
+                .collect::<Result<Vec<Item>, _>>()
+                .and_then(Rucksack::new)
+        })
+    }
+
+    fn part1(rucksacks: &Self::Input) -> Result<Self::Part1, aoc::Error> {
+        rucksacks
+            .iter()
+            .enumerate()
+            .map(|(idx, rucksack)| {
+                let item = rucksack
+                    .duplicate()
+                    .map_err(|err| aoc::Error::parse(idx + 1, err.column, err.message))?;
+                Ok(item.priority())
+            })
+            .sum()
+    }
+
+    fn part2(rucksacks: &Self::Input) -> Result<Self::Part2, aoc::Error> {
+        group::badge_sum(rucksacks, GROUP_SIZE)
+    }
+}
+
+pub mod item {
+    use aoc::ParseError;
+
+    #[repr(transparent)]
+    #[derive(Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct Item(u8);
+
+    impl Item {
+        /// The item with `priority`, which must be in `1..=52`.
+        fn from_priority(priority: usize) -> Self {
+            match priority {
+                1..=26 => Self(b'a' + (priority - 1) as u8),
+                27..=52 => Self(b'A' + (priority - 27) as u8),
+                _ => unreachable!(),
+            }
+        }
+
+        pub fn priority(self) -> usize {
+            match self {
+                Self(b'a'..=b'z') => 1 + (self.0 - b'a') as usize,
+                Self(b'A'..=b'Z') => 27 + (self.0 - b'A') as usize,
+                _ => unreachable!(),
+            }
+        }
+    }
+
+    impl std::fmt::Debug for Item {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{}", self.0 as char)
+        }
+    }
+
+    impl TryFrom<u8> for Item {
+        type Error = ParseError;
+
+        fn try_from(value: u8) -> Result<Self, Self::Error> {
+            match value {
+                b'a'..=b'z' | b'A'..=b'Z' => Ok(Item(value)),
+                _ => Err(ParseError::new(format!(
+                    "{:?} is not a valid item",
+                    value as char
+                ))),
+            }
+        }
+    }
+
+    /// A set of items, one bit per priority.
+    #[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+    pub struct ItemSet(u64);
+
+    impl ItemSet {
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        pub fn insert(&mut self, item: Item) {
+            self.0 |= 1 << item.priority();
+        }
+
+        pub fn contains(self, item: Item) -> bool {
+            self.0 & (1 << item.priority()) != 0
+        }
+
+        pub fn len(self) -> usize {
+            self.0.count_ones() as usize
+        }
+
+        pub fn is_empty(self) -> bool {
+            self.0 == 0
+        }
+
+        pub fn union(self, other: Self) -> Self {
+            Self(self.0 | other.0)
+        }
+
+        pub fn intersection(self, other: Self) -> Self {
+            Self(self.0 & other.0)
+        }
+
+        /// The items in the set, by increasing priority.
+        pub fn iter(self) -> impl Iterator<Item = Item> {
+            (1..=52)
+                .filter(move |priority| self.0 & (1 << priority) != 0)
+                .map(Item::from_priority)
+        }
+    }
+
+    impl FromIterator<Item> for ItemSet {
+        fn from_iter<I: IntoIterator<Item = Item>>(iter: I) -> Self {
+            let mut set = Self::new();
+            for item in iter {
+                set.insert(item);
+            }
+            set
+        }
+    }
+
+    impl std::fmt::Debug for ItemSet {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.debug_set().entries(self.iter()).finish()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::item::{Item, ItemSet};
+    use crate::Day3;
+    use aoc::Solution;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_sample_input() {
+        let rucksacks = Day3::parse(include_str!("sample-input.txt")).unwrap();
+        assert_eq!(Day3::part1(&rucksacks).unwrap(), 157);
+        assert_eq!(Day3::part2(&rucksacks).unwrap(), 70);
+    }
+
+    #[test]
+    fn test_errors_name_the_line() {
+        let err = Day3::parse("abac\nabc\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 1: 3 items cannot be split evenly between two compartments"
+        );
+
+        let rucksacks = Day3::parse("abac\nabcd\n").unwrap();
+        let err = Day3::part1(&rucksacks).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 1: no item is in both compartments"
+        );
+
+        let rucksacks = Day3::parse("aa\nbb\ncc\n").unwrap();
+        let err = Day3::part2(&rucksacks).unwrap_err();
+        assert_eq!(err.to_string(), "lines 1-3: no item is in every rucksack");
+    }
+
+    proptest! {
+        #[test]
+        fn item_accepts_only_letters(byte in any::<u8>()) {
+            let item = Item::try_from(byte);
+            prop_assert_eq!(item.is_ok(), byte.is_ascii_alphabetic());
+
+            if let Ok(item) = item {
+                let priority = item.priority();
+                let expected = if byte.is_ascii_lowercase() { 1..=26 } else { 27..=52 };
+                prop_assert!(expected.contains(&priority), "{item:?} has priority {priority}");
+            }
+        }
+
+        #[test]
+        fn item_set_agrees_with_btree_set(a in "[a-zA-Z]{0,30}", b in "[a-zA-Z]{0,30}") {
+            use std::collections::BTreeSet;
+
+            let items = |s: &str| s.bytes().map(|b| Item::try_from(b).unwrap()).collect::<Vec<_>>();
+            let priorities = |set: ItemSet| set.iter().map(Item::priority).collect::<Vec<_>>();
+            let (a, b) = (items(&a), items(&b));
+
+            let (set_a, set_b) = (a.iter().copied().collect::<ItemSet>(), b.iter().copied().collect());
+            let (tree_a, tree_b) = (
+                a.iter().map(|item| item.priority()).collect::<BTreeSet<_>>(),
+                b.iter().map(|item| item.priority()).collect::<BTreeSet<_>>(),
+            );
+
+            prop_assert_eq!(set_a.len(), tree_a.len());
+            prop_assert_eq!(priorities(set_a), tree_a.iter().copied().collect::<Vec<_>>());
+            prop_assert_eq!(
+                priorities(set_a.union(set_b)),
+                tree_a.union(&tree_b).copied().collect::<Vec<_>>()
+            );
+            prop_assert_eq!(
+                priorities(set_a.intersection(set_b)),
+                tree_a.intersection(&tree_b).copied().collect::<Vec<_>>()
+            );
+            prop_assert!(a.iter().all(|&item| set_a.contains(item)));
+        }
+
+        #[test]
+        fn parse_keeps_every_item(
+            lines in prop::collection::vec("([a-zA-Z]{2}){1,10}", 0..6),
+        ) {
+            let rucksacks = Day3::parse(&lines.join("\n")).unwrap();
+            let items = lines
+                .iter()
+                .map(|line| line.bytes().map(|b| Item::try_from(b).unwrap()).collect::<Vec<_>>())
+                .collect::<Vec<_>>();
+            let parsed = rucksacks.iter().map(|r| r.items().to_vec()).collect::<Vec<_>>();
+            prop_assert_eq!(parsed, items);
+        }
+
+        #[test]
+        fn parse_never_panics(input in "\\PC*") {
+            let _ = Day3::parse(&input);
+        }
+    }
+}
@@ -1,63 +1,81 @@
-use im::HashSet;
-use item::Item;
-use itertools::Itertools;
-
-fn main() -> color_eyre::Result<()> {
-    let sum: usize = include_str!("input.txt")
-        .lines()
-        .map(|line| {
-            line.bytes()
-                .map(|b| b.try_into().unwrap())
-                .collect::<HashSet<Item>>()
-        })
-        .chunks(3)
-        .into_iter()
-        .map(|chunks| {
-            chunks
-                .reduce(|a, b| a.intersection(b))
-                .expect("we always have 3 chunks")
-                .iter()
-                .next()
-                .expect("problem statement says there is always one item in common")
-                .priority()
-        })
-        .sum();
-
-    dbg!(sum);
-    Ok(())
+use std::num::NonZeroUsize;
+use std::process::ExitCode;
+use std::time::Instant;
+
+use aoc::{Answer, DayArgs, InputArgs, Part, Solution};
+use clap::{Parser, Subcommand};
+use day3::group::{self, GROUP_SIZE};
+use day3::Day3;
+
+/// Advent of Code 2022, day 3: Rucksack Reorganization
+#[derive(Parser)]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    #[command(flatten)]
+    args: DayArgs,
+
+    /// Number of elves in each group sharing a badge
+    #[arg(long, default_value_t = NonZeroUsize::new(GROUP_SIZE).unwrap(), global = true)]
+    group_size: NonZeroUsize,
 }
 
-mod item {
-    use color_eyre::{eyre::eyre, Report};
+#[derive(Subcommand)]
+enum Command {
+    /// List the groups that do not have exactly one badge
+    Check {
+        #[command(flatten)]
+        input: InputArgs,
+    },
+}
 
-    #[repr(transparent)]
-    #[derive(Clone, Copy, PartialEq, Eq, Hash)]
-    pub(crate) struct Item(u8);
+fn main() -> ExitCode {
+    let cli = aoc::parse_args::<Cli>();
+    let size = cli.group_size.get();
 
-    impl Item {
-        pub(crate) fn priority(self) -> usize {
-            match self {
-                Self(b'a'..=b'z') => 1 + (self.0 - b'a') as usize,
-                Self(b'A'..=b'Z') => 27 + (self.0 - b'A') as usize,
-                _ => unreachable!(),
-            }
-        }
-    }
+    aoc::exit_code(match cli.command {
+        None if size == GROUP_SIZE => cli.args.solve::<Day3>(&Part::ALL),
+        None => solve(&cli.args, size),
+        Some(Command::Check { input }) => check(&input, size),
+    })
+}
 
-    impl std::fmt::Debug for Item {
-        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-            write!(f, "{}", self.0 as char)
-        }
-    }
+fn solve(args: &DayArgs, size: usize) -> Result<(), aoc::Error> {
+    let input = args.input.read(Day3::DAY)?;
+    let start = Instant::now();
+    let rucksacks = Day3::parse(&input)?;
+    let parse = start.elapsed();
 
-    impl TryFrom<u8> for Item {
-        type Error = Report;
+    let mut answers = aoc::answer::<Day3>(&rucksacks, parse, &[Part::One])?;
 
-        fn try_from(value: u8) -> Result<Self, Self::Error> {
-            match value {
-                b'a'..=b'z' | b'A'..=b'Z' => Ok(Item(value)),
-                _ => Err(eyre!("{} is not a valid item", value as char)),
+    let start = Instant::now();
+    let badges = group::badge_sum(&rucksacks, size)?;
+    answers.push(Answer {
+        part: Part::Two,
+        answer: badges.to_string(),
+        parse,
+        solve: start.elapsed(),
+    });
+
+    aoc::output::print(args.format, Day3::DAY, &input, &answers);
+    Ok(())
+}
+
+fn check(input: &InputArgs, size: usize) -> Result<(), aoc::Error> {
+    let rucksacks = Day3::parse(&input.read(Day3::DAY)?)?;
+    let groups = group::groups(&rucksacks, size).count();
+
+    match group::badges(&rucksacks, size) {
+        Ok(_) => {
+            println!("all {groups} groups of {size} have exactly one badge");
+            Ok(())
+        }
+        Err(problems) => {
+            for problem in &problems {
+                println!("{problem}");
             }
+            Err(format!("{} of {groups} groups have no badge", problems.len()).into())
         }
     }
 }
@@ -0,0 +1,83 @@
+use aoc::ParseError;
+
+use crate::item::{Item, ItemSet};
+
+/// The items of a rucksack, half of them in each of its two compartments.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rucksack(Vec<Item>);
+
+impl Rucksack {
+    pub fn new(items: Vec<Item>) -> Result<Self, ParseError> {
+        if !items.len().is_multiple_of(2) {
+            return Err(ParseError::new(format!(
+                "{} items cannot be split evenly between two compartments",
+                items.len()
+            )));
+        }
+
+        Ok(Self(items))
+    }
+
+    pub fn items(&self) -> &[Item] {
+        &self.0
+    }
+
+    pub fn compartments(&self) -> (&[Item], &[Item]) {
+        self.0.split_at(self.0.len() / 2)
+    }
+
+    /// The one kind of item packed in both compartments.
+    pub fn duplicate(&self) -> Result<Item, ParseError> {
+        let (left, right) = self.compartments();
+        let left = left.iter().copied().collect::<ItemSet>();
+        let right = right.iter().copied().collect::<ItemSet>();
+        let both = left.intersection(right);
+
+        match both.iter().collect::<Vec<_>>()[..] {
+            [item] => Ok(item),
+            [] => Err(ParseError::new("no item is in both compartments")),
+            _ => Err(ParseError::new(format!(
+                "items {both:?} are all in both compartments"
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rucksack(items: &str) -> Result<Rucksack, ParseError> {
+        Rucksack::new(items.bytes().map(|b| Item::try_from(b).unwrap()).collect())
+    }
+
+    #[test]
+    fn compartments_split_in_half() {
+        let rucksack = rucksack("vJrwpWtwJgWrhcsFMMfFFhFp").unwrap();
+        let (left, right) = rucksack.compartments();
+
+        assert_eq!(format!("{left:?}"), "[v, J, r, w, p, W, t, w, J, g, W, r]");
+        assert_eq!(format!("{right:?}"), "[h, c, s, F, M, M, f, F, F, h, F, p]");
+        assert_eq!(rucksack.duplicate().unwrap().priority(), 16);
+    }
+
+    #[test]
+    fn odd_item_counts_are_rejected() {
+        assert_eq!(
+            rucksack("abc").unwrap_err().message,
+            "3 items cannot be split evenly between two compartments"
+        );
+    }
+
+    #[test]
+    fn duplicate_must_be_unique() {
+        assert_eq!(
+            rucksack("abcd").unwrap().duplicate().unwrap_err().message,
+            "no item is in both compartments"
+        );
+        assert_eq!(
+            rucksack("abab").unwrap().duplicate().unwrap_err().message,
+            "items {a, b} are all in both compartments"
+        );
+    }
+}
@@ -0,0 +1,40 @@
+use std::process::{Command, Output};
+
+const SAMPLE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/sample-input.txt");
+
+fn day3(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_day3"))
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+#[test]
+fn group_size_before_subcommand() {
+    let output = day3(&["--group-size", "2", "check", SAMPLE]);
+
+    assert!(!output.status.success());
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout),
+        concat!(
+            "lines 1-2: items {f, r, s, F, M} are all in every rucksack\n",
+            "lines 3-4: items {q, v, w, B, T} are all in every rucksack\n",
+            "lines 5-6: items {G, J, Z} are all in every rucksack\n",
+        )
+    );
+    assert_eq!(
+        String::from_utf8_lossy(&output.stderr),
+        "error: 3 of 3 groups have no badge\n"
+    );
+}
+
+#[test]
+fn group_size_after_subcommand() {
+    let output = day3(&["check", "--group-size", "3", SAMPLE]);
+
+    assert!(output.status.success(), "{output:?}");
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout),
+        "all 2 groups of 3 have exactly one badge\n"
+    );
+}
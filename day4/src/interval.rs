@@ -0,0 +1,284 @@
+//! Set operations on inclusive ranges.
+//!
+//! A range whose start is greater than its end is empty, as with [`RangeInclusive::is_empty`].
+//! Empty ranges overlap nothing, are contained in every range, and are left out of unions and
+//! differences.
+
+use std::fmt::{self, Display, Formatter};
+use std::ops::RangeInclusive;
+
+/// How two ranges relate, from the point of view of the first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Relation {
+    Disjoint,
+    Overlapping,
+    LeftContainsRight,
+    RightContainsLeft,
+    Equal,
+}
+
+impl Relation {
+    pub const ALL: [Relation; 5] = [
+        Relation::Disjoint,
+        Relation::Overlapping,
+        Relation::LeftContainsRight,
+        Relation::RightContainsLeft,
+        Relation::Equal,
+    ];
+
+    /// Whether one range contains the other.
+    pub fn is_containment(self) -> bool {
+        matches!(
+            self,
+            Self::LeftContainsRight | Self::RightContainsLeft | Self::Equal
+        )
+    }
+
+    /// Whether the ranges have any value in common.
+    pub fn is_overlap(self) -> bool {
+        self != Self::Disjoint
+    }
+}
+
+impl Display for Relation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.pad(match self {
+            Self::Disjoint => "disjoint",
+            Self::Overlapping => "overlapping",
+            Self::LeftContainsRight => "left-contains-right",
+            Self::RightContainsLeft => "right-contains-left",
+            Self::Equal => "equal",
+        })
+    }
+}
+
+pub trait InclusiveRangeExt<T> {
+    /// Whether every value of `other` is also in `self`.
+    fn contains_range(&self, other: &Self) -> bool;
+
+    fn contains_range_or_is_contained(&self, other: &Self) -> bool {
+        self.contains_range(other) || other.contains_range(self)
+    }
+
+    /// Whether some value is in both ranges.
+    fn overlaps(&self, other: &Self) -> bool;
+
+    /// The values in both ranges, which may be an empty range.
+    fn intersection(&self, other: &Self) -> Self;
+
+    /// How `self` relates to `other`. Two empty ranges are equal, and an empty range is
+    /// contained in any other.
+    fn relation(&self, other: &Self) -> Relation {
+        match (self.contains_range(other), other.contains_range(self)) {
+            (true, true) => Relation::Equal,
+            (true, false) => Relation::LeftContainsRight,
+            (false, true) => Relation::RightContainsLeft,
+            (false, false) if self.overlaps(other) => Relation::Overlapping,
+            (false, false) => Relation::Disjoint,
+        }
+    }
+}
+
+impl<T: Ord + Clone> InclusiveRangeExt<T> for RangeInclusive<T> {
+    fn contains_range(&self, other: &Self) -> bool {
+        other.is_empty() || (self.start() <= other.start() && other.end() <= self.end())
+    }
+
+    fn overlaps(&self, other: &Self) -> bool {
+        !self.intersection(other).is_empty()
+    }
+
+    fn intersection(&self, other: &Self) -> Self {
+        let start = self.start().max(other.start()).clone();
+        let end = self.end().min(other.end()).clone();
+        start..=end
+    }
+}
+
+/// Types whose values can be counted, so ranges of them have a length and can have holes cut in
+/// them.
+pub trait Discrete: Ord + Copy {
+    fn succ(self) -> Self;
+    fn pred(self) -> Self;
+    fn to_i128(self) -> i128;
+}
+
+macro_rules! impl_discrete {
+    ($($t:ty),*) => {
+        $(
+            impl Discrete for $t {
+                fn succ(self) -> Self {
+                    self + 1
+                }
+
+                fn pred(self) -> Self {
+                    self - 1
+                }
+
+                fn to_i128(self) -> i128 {
+                    self as i128
+                }
+            }
+        )*
+    };
+}
+
+impl_discrete!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+pub trait DiscreteRangeExt<T>: InclusiveRangeExt<T> {
+    /// The number of values in the range, saturating at `u64::MAX` for the whole of a 64-bit type.
+    fn length(&self) -> u64;
+
+    /// The values in either range, or `None` if that is not a single range because there are
+    /// values between the ranges. Ranges that overlap or are adjacent, like `1..=3` and `4..=6`,
+    /// join.
+    fn union(&self, other: &Self) -> Option<Self>
+    where
+        Self: Sized;
+
+    /// The values in `self` but not in `other`, as up to two non-empty ranges in order.
+    fn difference(&self, other: &Self) -> Vec<Self>
+    where
+        Self: Sized;
+}
+
+impl<T: Discrete> DiscreteRangeExt<T> for RangeInclusive<T> {
+    fn length(&self) -> u64 {
+        if self.is_empty() {
+            return 0;
+        }
+
+        let len = self.end().to_i128() - self.start().to_i128() + 1;
+        len.min(u64::MAX as i128) as u64
+    }
+
+    fn union(&self, other: &Self) -> Option<Self> {
+        if other.is_empty() {
+            return Some(self.clone());
+        }
+        if self.is_empty() {
+            return Some(other.clone());
+        }
+
+        let (first, second) = if self.start() <= other.start() {
+            (self, other)
+        } else {
+            (other, self)
+        };
+        // `first` only ends before `second` starts if its end has a successor.
+        (second.start() <= first.end() || first.end().succ() == *second.start())
+            .then(|| *first.start()..=*first.end().max(second.end()))
+    }
+
+    fn difference(&self, other: &Self) -> Vec<Self> {
+        if self.is_empty() {
+            return vec![];
+        }
+        if !self.overlaps(other) {
+            return vec![self.clone()];
+        }
+
+        let mut pieces = vec![];
+        // `other` starts after `self` does, so has a predecessor, and likewise for its end.
+        if self.start() < other.start() {
+            pieces.push(*self.start()..=other.start().pred());
+        }
+        if other.end() < self.end() {
+            pieces.push(other.end().succ()..=*self.end());
+        }
+        pieces
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn values(range: &RangeInclusive<u8>) -> Vec<u8> {
+        range.clone().collect()
+    }
+
+    #[test]
+    fn empty_ranges() {
+        #[allow(clippy::reversed_empty_ranges)]
+        let empty = 5..=3;
+
+        assert!(!(1..=10).overlaps(&empty));
+        assert!((1..=10).contains_range(&empty));
+        assert!(!empty.contains_range(&(4..=4)));
+        assert_eq!((1..=10).union(&empty), Some(1..=10));
+        assert_eq!(empty.length(), 0);
+        assert!(empty.difference(&(1..=10)).is_empty());
+    }
+
+    #[test]
+    fn difference_cuts_a_hole() {
+        assert_eq!((1..=10).difference(&(3..=5)), [1..=2, 6..=10]);
+        assert_eq!((1..=10).difference(&(0..=5)), [6..=10]);
+        assert_eq!((1..=10).difference(&(0..=10)), []);
+        assert_eq!((1..=10).difference(&(11..=12)), [1..=10]);
+        assert_eq!((0..=u8::MAX).difference(&(0..=0)), [1..=u8::MAX]);
+    }
+
+    #[test]
+    fn union_joins_adjacent_ranges() {
+        assert_eq!((1..=3).union(&(4..=6)), Some(1..=6));
+        assert_eq!((4..=6).union(&(1..=3)), Some(1..=6));
+        assert_eq!((1..=3).union(&(5..=6)), None);
+        assert_eq!((0..=u8::MAX).union(&(u8::MAX..=u8::MAX)), Some(0..=u8::MAX));
+        assert_eq!((5..=9).union(&(0..=u8::MAX)), Some(0..=u8::MAX));
+    }
+
+    #[test]
+    fn length_of_whole_types() {
+        assert_eq!((0..=u64::MAX).length(), u64::MAX);
+        assert_eq!((i64::MIN..=-1).length(), 1 << 63);
+        assert_eq!((2..=4u32).length(), 3);
+    }
+
+    #[test]
+    fn relations() {
+        assert_eq!((2..=4).relation(&(6..=8)), Relation::Disjoint);
+        assert_eq!((5..=7).relation(&(7..=9)), Relation::Overlapping);
+        assert_eq!((2..=8).relation(&(3..=7)), Relation::LeftContainsRight);
+        assert_eq!((6..=6).relation(&(4..=6)), Relation::RightContainsLeft);
+        assert_eq!((3..=3).relation(&(3..=3)), Relation::Equal);
+    }
+
+    proptest! {
+        #[test]
+        fn overlaps_is_symmetric(a: (u8, u8), b: (u8, u8)) {
+            let (a, b) = (a.0..=a.1, b.0..=b.1);
+            prop_assert_eq!(a.overlaps(&b), b.overlaps(&a));
+        }
+
+        #[test]
+        fn operations_agree_with_the_values_in_the_ranges(a: (u8, u8), b: (u8, u8)) {
+            let (a, b) = (a.0..=a.1, b.0..=b.1);
+            let (in_a, in_b) = (values(&a), values(&b));
+
+            let both = in_a.iter().copied().filter(|v| b.contains(v)).collect::<Vec<_>>();
+            prop_assert_eq!(values(&a.intersection(&b)), both.clone());
+            prop_assert_eq!(a.overlaps(&b), !both.is_empty());
+            prop_assert_eq!(a.contains_range(&b), in_b.iter().all(|v| a.contains(v)));
+            prop_assert_eq!(a.length(), in_a.len() as u64);
+
+            let only_a = in_a.iter().copied().filter(|v| !b.contains(v)).collect::<Vec<_>>();
+            let difference = a.difference(&b);
+            prop_assert!(difference.iter().all(|piece| !piece.is_empty()));
+            prop_assert_eq!(difference.iter().flat_map(values).collect::<Vec<_>>(), only_a);
+
+            let mut either = [in_a, in_b].concat();
+            either.sort_unstable();
+            either.dedup();
+            if either.windows(2).all(|pair| pair[0] + 1 == pair[1]) {
+                let union = a.union(&b);
+                prop_assert!(union.is_some(), "{:?} and {:?} should join", a, b);
+                prop_assert_eq!(values(&union.unwrap()), either);
+            } else {
+                prop_assert_eq!(a.union(&b), None);
+            }
+        }
+    }
+}
@@ -0,0 +1,152 @@
+use std::collections::BTreeMap;
+use std::fmt::{self, Display, Formatter};
+use std::ops::RangeInclusive;
+
+use aoc::Solution;
+use interval::{InclusiveRangeExt, Relation};
+use parser::Pair;
+
+pub mod interval;
+pub mod parser;
+pub mod sweep;
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+
+    type Input = Vec<(RangeInclusive<u32>, RangeInclusive<u32>)>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, aoc::Error> {
+        Ok(parser::parse(input)?)
+    }
+
+    fn part1(pairs: &Self::Input) -> Result<Self::Part1, aoc::Error> {
+        Ok(pairs
+            .iter()
+            .filter(|(a, b)| a.relation(b).is_containment())
+            .count())
+    }
+
+    fn part2(pairs: &Self::Input) -> Result<Self::Part2, aoc::Error> {
+        Ok(pairs
+            .iter()
+            .filter(|(a, b)| a.relation(b).is_overlap())
+            .count())
+    }
+}
+
+/// Each pair, with the line it is on, and how its two assignments relate, followed by how many
+/// pairs relate each way.
+pub struct Explanation<'a>(pub &'a [(usize, Pair)]);
+
+impl Display for Explanation<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let range = |range: &RangeInclusive<u32>| format!("{}-{}", range.start(), range.end());
+
+        writeln!(
+            f,
+            "{:>5}  {:<21}  {:<21}  relation",
+            "line", "left", "right"
+        )?;
+
+        let mut counts = BTreeMap::new();
+        for (line, (left, right)) in self.0 {
+            let relation = left.relation(right);
+            *counts.entry(relation).or_insert(0) += 1;
+
+            writeln!(
+                f,
+                "{:>5}  {:<21}  {:<21}  {relation}",
+                line,
+                range(left),
+                range(right)
+            )?;
+        }
+
+        writeln!(f)?;
+        for relation in Relation::ALL {
+            let count = counts.get(&relation).unwrap_or(&0);
+            writeln!(f, "{relation:<19}  {count:>6}")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_sample_input() {
+        let pairs = Day4::parse(include_str!("sample-input.txt")).unwrap();
+        assert_eq!(Day4::part1(&pairs).unwrap(), 2);
+        assert_eq!(Day4::part2(&pairs).unwrap(), 4);
+    }
+
+    #[test]
+    fn test_explanation_of_sample_input() {
+        let pairs = parser::parse_numbered(include_str!("sample-input.txt")).unwrap();
+        assert_eq!(
+            Explanation(&pairs).to_string(),
+            concat!(
+                " line  left                   right                  relation\n",
+                "    1  2-4                    6-8                    disjoint\n",
+                "    2  2-3                    4-5                    disjoint\n",
+                "    3  5-7                    7-9                    overlapping\n",
+                "    4  2-8                    3-7                    left-contains-right\n",
+                "    5  6-6                    4-6                    right-contains-left\n",
+                "    6  2-6                    4-8                    overlapping\n",
+                "\n",
+                "disjoint                  2\n",
+                "overlapping               2\n",
+                "left-contains-right       1\n",
+                "right-contains-left       1\n",
+                "equal                     0\n",
+            )
+        );
+    }
+
+    #[test]
+    fn test_explanation_numbers_pairs_by_line() {
+        let pairs = parser::parse_numbered("2-4,6-8\n\n5-7,7-9\n").unwrap();
+        assert_eq!(
+            Explanation(&pairs).to_string().lines().nth(2),
+            Some("    3  5-7                    7-9                    overlapping")
+        );
+
+        let err = Day4::parse("2-4,6-8\n\n5-x,7-9\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 3, column 3: expected a section number, got \"x\""
+        );
+    }
+
+    #[test]
+    fn test_parse_reports_bad_section() {
+        let err = Day4::parse("2-4,6-x\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 7: expected a section number, got \"x\""
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn pair_round_trips(a: u32, b: u32, c: u32, d: u32) {
+            let (a, b) = (a.min(b), a.max(b));
+            let (c, d) = (c.min(d), c.max(d));
+            let pairs = Day4::parse(&format!("{a}-{b},{c}-{d}")).unwrap();
+            prop_assert_eq!(pairs, [(a..=b, c..=d)]);
+        }
+
+        #[test]
+        fn parse_never_panics(input in "([0-9,-]{0,12}\n){0,5}|\\PC*") {
+            let _ = Day4::parse(&input);
+        }
+    }
+}
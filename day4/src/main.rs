@@ -1,52 +1,88 @@
-use std::ops::RangeInclusive;
+use std::process::ExitCode;
+use std::time::Instant;
 
-use itertools::Itertools;
+use aoc::{DayArgs, InputArgs, Part, Solution};
+use clap::{Parser, Subcommand};
+use day4::parser;
+use day4::sweep::Coverage;
+use day4::{Day4, Explanation};
 
-trait InclusiveRangeExt {
-    fn contains_range(&self, other: &Self) -> bool;
+/// Advent of Code 2022, day 4: Camp Cleanup
+#[derive(Parser)]
+#[command(args_conflicts_with_subcommands = true)]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
 
-    fn contains_range_or_is_contained(&self, other: &Self) -> bool {
-        self.contains_range(other) || other.contains_range(self)
-    }
+    #[command(flatten)]
+    args: DayArgs,
+
+    /// List each pair with how its assignments relate before the answers
+    #[arg(long, conflicts_with = "format")]
+    explain: bool,
+}
 
-    fn overlaps(&self, other: &Self) -> bool;
+#[derive(Subcommand)]
+enum Command {
+    /// Show how the assignments of all elves cover the sections
+    Coverage {
+        /// Count the sections assigned to more than this many elves
+        #[arg(long, default_value_t = 1)]
+        more_than: usize,
 
-    fn overlaps_or_is_overlapped(&self, other: &Self) -> bool {
-        self.overlaps(other) || other.overlaps(self)
-    }
+        #[command(flatten)]
+        input: InputArgs,
+    },
 }
 
-impl<T> InclusiveRangeExt for RangeInclusive<T>
-where
-    T: PartialOrd,
-{
-    fn contains_range(&self, other: &Self) -> bool {
-        self.contains(other.start()) && self.contains(other.end())
-    }
+fn main() -> ExitCode {
+    let cli = Cli::parse();
 
-    fn overlaps(&self, other: &Self) -> bool {
-        self.contains(other.start()) || self.contains(other.end())
-    }
+    aoc::exit_code(match cli.command {
+        None if cli.explain => explain(&cli.args),
+        None => cli.args.solve::<Day4>(&Part::ALL),
+        Some(Command::Coverage { more_than, input }) => coverage(more_than, &input),
+    })
+}
+
+fn explain(args: &DayArgs) -> Result<(), aoc::Error> {
+    let input = args.input.read(Day4::DAY)?;
+    let start = Instant::now();
+    let numbered = parser::parse_numbered(&input)?;
+    let parse = start.elapsed();
+
+    print!("{}", Explanation(&numbered));
+    println!();
+
+    let pairs = numbered.into_iter().map(|(_, pair)| pair).collect();
+    let answers = aoc::answer::<Day4>(&pairs, parse, &Part::ALL)?;
+    aoc::output::print(args.format, Day4::DAY, &input, &answers);
+    Ok(())
 }
 
-fn main() {
-    let redundant = include_str!("input.txt")
-        .lines()
-        .map(|line| {
-            line.split(',')
-                .map(|range| {
-                    range
-                        .split('-')
-                        .map(|n| n.parse().expect("range start/end should be u32"))
-                        .collect_tuple::<(u32, u32)>()
-                        .map(|(start, end)| start..=end)
-                        .expect("each range should have a start and an end")
-                })
-                .collect_tuple::<(_, _)>()
-                .expect("each line must have a pair of ranges")
-        })
-        .filter(|(a, b)| a.overlaps_or_is_overlapped(b))
-        .count();
-
-    dbg!(redundant);
+fn coverage(more_than: usize, input: &InputArgs) -> Result<(), aoc::Error> {
+    let pairs = Day4::parse(&input.read(Day4::DAY)?)?;
+    let coverage = Coverage::of(pairs.iter().flat_map(|(a, b)| [a, b]));
+
+    let gaps = coverage
+        .gaps()
+        .iter()
+        .map(|gap| format!("{}-{}", gap.start(), gap.end()))
+        .collect::<Vec<_>>();
+
+    println!("sections covered: {}", coverage.covered());
+    println!(
+        "sections assigned to more than {more_than} elves: {}",
+        coverage.more_than(more_than)
+    );
+    println!("most elves on one section: {}", coverage.max_depth());
+    if gaps.is_empty() {
+        println!("gaps: none");
+    } else {
+        println!("gaps: {}", gaps.join(", "));
+    }
+    println!();
+    print!("{coverage}");
+
+    Ok(())
 }
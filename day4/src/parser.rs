@@ -0,0 +1,279 @@
+//! Parses lines of assignment pairs like `2-4,6-8`.
+//!
+//! Spaces and tabs are allowed around sections, dashes and commas, `\r\n` line endings are
+//! accepted, and blank lines are skipped.
+
+use std::fmt::{self, Display, Formatter};
+use std::ops::RangeInclusive;
+
+use aoc::ParseError;
+use nom::bytes::complete::take_till;
+use nom::character::complete::{char, digit1, space0};
+use nom::sequence::preceded;
+use nom::IResult;
+
+pub type Pair = (RangeInclusive<u32>, RangeInclusive<u32>);
+
+/// Why a line is not a pair of assignments.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Reason {
+    NotASection,
+    SectionTooLarge,
+    MissingDash,
+    MissingComma,
+    StartGreaterThanEnd,
+    TrailingInput,
+}
+
+impl Display for Reason {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::NotASection => "expected a section number",
+            Self::SectionTooLarge => "section number does not fit in u32",
+            Self::MissingDash => "expected '-' between the start and end of a range",
+            Self::MissingComma => "expected ',' between the two ranges",
+            Self::StartGreaterThanEnd => "start greater than end",
+            Self::TrailingInput => "unexpected input after the second range",
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PairError {
+    /// 1-based.
+    pub line: usize,
+    /// 1-based, in characters.
+    pub column: usize,
+    /// The offending part of the line, empty when something is missing at its end.
+    pub token: String,
+    pub reason: Reason,
+}
+
+impl PairError {
+    /// The reason and token, without the position.
+    pub fn message(&self) -> String {
+        if self.token.is_empty() {
+            format!("{} at end of line", self.reason)
+        } else {
+            format!("{}, got {:?}", self.reason, self.token)
+        }
+    }
+}
+
+impl Display for PairError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line,
+            self.column,
+            self.message()
+        )
+    }
+}
+
+impl std::error::Error for PairError {}
+
+impl From<PairError> for aoc::Error {
+    fn from(err: PairError) -> Self {
+        aoc::Error::parse(err.line, err.column, err.message())
+    }
+}
+
+/// A failure at `token`, a slice of the line being parsed.
+struct Failure<'a> {
+    token: &'a str,
+    reason: Reason,
+}
+
+impl<'a> Failure<'a> {
+    fn new(token: &'a str, reason: Reason) -> Self {
+        Self { token, reason }
+    }
+}
+
+/// The next run of characters up to a separator.
+fn word(input: &str) -> IResult<&str, &str> {
+    preceded(
+        space0,
+        take_till(|c: char| c == '-' || c == ',' || c.is_whitespace()),
+    )(input)
+}
+
+/// What to blame when `input` does not start as expected: the next word, or the next character
+/// if that is a separator, or nothing at the end of the line.
+fn blame(input: &str) -> &str {
+    match word(input) {
+        Ok((_, token)) if !token.is_empty() => token,
+        _ => {
+            let rest = input.trim_start();
+            rest.chars().next().map_or(rest, |c| &rest[..c.len_utf8()])
+        }
+    }
+}
+
+fn section(input: &str) -> Result<(&str, u32), Failure<'_>> {
+    let Ok((rest, digits)) = preceded(space0, digit1::<_, ()>)(input) else {
+        return Err(Failure::new(blame(input), Reason::NotASection));
+    };
+
+    match digits.parse() {
+        Ok(section) => Ok((rest, section)),
+        Err(_) => Err(Failure::new(digits, Reason::SectionTooLarge)),
+    }
+}
+
+/// Consumes `separator` after optional spaces, or blames whatever is there instead.
+fn separator(input: &str, separator: char, reason: Reason) -> Result<&str, Failure<'_>> {
+    match preceded(space0, char::<_, ()>(separator))(input) {
+        Ok((rest, _)) => Ok(rest),
+        Err(_) => Err(Failure::new(blame(input), reason)),
+    }
+}
+
+fn range(input: &str) -> Result<(&str, RangeInclusive<u32>), Failure<'_>> {
+    let (rest, start) = section(input)?;
+    let rest = separator(rest, '-', Reason::MissingDash)?;
+    let (rest, end) = section(rest)?;
+
+    if start > end {
+        let range = input.trim_start_matches([' ', '\t']);
+        let token = &range[..range.len() - rest.len()];
+        return Err(Failure::new(token, Reason::StartGreaterThanEnd));
+    }
+
+    Ok((rest, start..=end))
+}
+
+fn pair(line: &str) -> Result<Pair, Failure<'_>> {
+    let (rest, left) = range(line)?;
+    let rest = separator(rest, ',', Reason::MissingComma)?;
+    let (rest, right) = range(rest)?;
+
+    let rest = rest.trim();
+    if !rest.is_empty() {
+        return Err(Failure::new(rest, Reason::TrailingInput));
+    }
+
+    Ok((left, right))
+}
+
+/// Parses every line of `input`, stopping at the first that is not a pair.
+pub fn parse(input: &str) -> Result<Vec<Pair>, PairError> {
+    Ok(parse_numbered(input)?
+        .into_iter()
+        .map(|(_, pair)| pair)
+        .collect())
+}
+
+/// [`parse`], keeping the 1-based line each pair is on, which differs from its position once
+/// blank lines are skipped.
+pub fn parse_numbered(input: &str) -> Result<Vec<(usize, Pair)>, PairError> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(idx, line)| {
+            let pair = pair(line).map_err(|failure| PairError {
+                line: idx + 1,
+                column: ParseError::at(line, failure.token, "").column,
+                token: failure.token.to_owned(),
+                reason: failure.reason,
+            })?;
+            Ok((idx + 1, pair))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn error(input: &str) -> (usize, usize, String, Reason) {
+        let err = parse(input).unwrap_err();
+        (err.line, err.column, err.token, err.reason)
+    }
+
+    #[test]
+    fn whitespace_and_crlf_are_tolerated() {
+        let pairs = parse(" 2 - 4 ,\t6-8 \r\n\r\n5-7,7-9\r\n").unwrap();
+        assert_eq!(pairs, [(2..=4, 6..=8), (5..=7, 7..=9)]);
+    }
+
+    #[test]
+    fn blank_lines_keep_line_numbers() {
+        let pairs = parse_numbered("2-4,6-8\n\n5-7,7-9\n").unwrap();
+        assert_eq!(pairs, [(1, (2..=4, 6..=8)), (3, (5..=7, 7..=9))]);
+    }
+
+    #[test]
+    fn not_a_section() {
+        assert_eq!(
+            error("2-4,6-x\n"),
+            (1, 7, "x".to_string(), Reason::NotASection)
+        );
+        assert_eq!(error("2-4,6-"), (1, 7, "".to_string(), Reason::NotASection));
+    }
+
+    #[test]
+    fn separator_where_a_section_should_be() {
+        assert_eq!(
+            error("2-4,-5"),
+            (1, 5, "-".to_string(), Reason::NotASection)
+        );
+    }
+
+    #[test]
+    fn section_too_large() {
+        assert_eq!(
+            error("1-2,3-4\n1-99999999999,3-4\n"),
+            (2, 3, "99999999999".to_string(), Reason::SectionTooLarge)
+        );
+    }
+
+    #[test]
+    fn missing_dash() {
+        assert_eq!(
+            error("2+4,6-8"),
+            (1, 2, "+4".to_string(), Reason::MissingDash)
+        );
+    }
+
+    #[test]
+    fn missing_comma() {
+        assert_eq!(
+            error("2-4 6-8"),
+            (1, 5, "6".to_string(), Reason::MissingComma)
+        );
+        assert_eq!(error("2-4"), (1, 4, "".to_string(), Reason::MissingComma));
+    }
+
+    #[test]
+    fn start_greater_than_end() {
+        let err = parse("2-4,8 - 6\n").unwrap_err();
+        assert_eq!(
+            (err.column, err.token.as_str(), err.reason),
+            (5, "8 - 6", Reason::StartGreaterThanEnd)
+        );
+        assert_eq!(
+            aoc::Error::from(err).to_string(),
+            "line 1, column 5: start greater than end, got \"8 - 6\""
+        );
+    }
+
+    #[test]
+    fn trailing_input() {
+        assert_eq!(
+            error("2-4,6-8,1-2"),
+            (1, 8, ",1-2".to_string(), Reason::TrailingInput)
+        );
+    }
+
+    #[test]
+    fn missing_input_is_reported_at_end_of_line() {
+        assert_eq!(
+            parse("2-4,").unwrap_err().to_string(),
+            "line 1, column 5: expected a section number at end of line"
+        );
+    }
+}
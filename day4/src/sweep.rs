@@ -0,0 +1,189 @@
+//! How the section assignments of all elves cover the sections, found by sweeping over the
+//! points where assignments start and end.
+
+use std::fmt::{self, Display, Formatter};
+use std::ops::RangeInclusive;
+
+use crate::interval::DiscreteRangeExt;
+
+/// A run of sections each assigned to the same number of elves.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Segment {
+    pub sections: RangeInclusive<u32>,
+    pub elves: usize,
+}
+
+/// The sections from the lowest assigned to the highest, split into runs assigned to the same
+/// number of elves. Runs assigned to none are gaps.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Coverage(pub Vec<Segment>);
+
+impl Coverage {
+    /// Sweeps over `assignments`, ignoring empty ones.
+    pub fn of<'a>(assignments: impl IntoIterator<Item = &'a RangeInclusive<u32>>) -> Self {
+        // Ends are one past the assignment so that `u32::MAX` can end one.
+        let mut events = assignments
+            .into_iter()
+            .filter(|range| !range.is_empty())
+            .flat_map(|range| [(*range.start() as u64, 1), (*range.end() as u64 + 1, -1)])
+            .collect::<Vec<(u64, isize)>>();
+        events.sort_unstable();
+
+        let mut segments = Vec::<Segment>::new();
+        let mut elves = 0isize;
+        let mut events = events.into_iter().peekable();
+
+        while let Some((start, change)) = events.next() {
+            elves += change;
+            while let Some((_, change)) = events.next_if(|&(at, _)| at == start) {
+                elves += change;
+            }
+
+            let Some(&(end, _)) = events.peek() else {
+                break;
+            };
+
+            let sections = start as u32..=(end - 1) as u32;
+            match segments.last_mut() {
+                Some(last) if last.elves == elves as usize => {
+                    last.sections = *last.sections.start()..=*sections.end();
+                }
+                _ => segments.push(Segment {
+                    sections,
+                    elves: elves as usize,
+                }),
+            }
+        }
+
+        Self(segments)
+    }
+
+    /// The number of sections assigned to more than `elves` elves.
+    pub fn more_than(&self, elves: usize) -> u64 {
+        self.0
+            .iter()
+            .filter(|segment| segment.elves > elves)
+            .map(|segment| segment.sections.length())
+            .sum()
+    }
+
+    /// The number of sections assigned to any elf.
+    pub fn covered(&self) -> u64 {
+        self.more_than(0)
+    }
+
+    /// Sections between assigned ones that no elf is assigned.
+    pub fn gaps(&self) -> Vec<RangeInclusive<u32>> {
+        self.0
+            .iter()
+            .filter(|segment| segment.elves == 0)
+            .map(|segment| segment.sections.clone())
+            .collect()
+    }
+
+    /// The most elves any one section is assigned to.
+    pub fn max_depth(&self) -> usize {
+        self.0
+            .iter()
+            .map(|segment| segment.elves)
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+impl Display for Coverage {
+    /// A chart with a row per segment and a `#` per elf.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{:>10}  {:>10}  {:>5}", "start", "end", "elves")?;
+
+        for segment in &self.0 {
+            let row = format!(
+                "{:>10}  {:>10}  {:>5}  {}",
+                segment.sections.start(),
+                segment.sections.end(),
+                segment.elves,
+                "#".repeat(segment.elves)
+            );
+            writeln!(f, "{}", row.trim_end())?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day4;
+    use aoc::Solution;
+
+    fn segment(sections: RangeInclusive<u32>, elves: usize) -> Segment {
+        Segment { sections, elves }
+    }
+
+    fn coverage(input: &str) -> Coverage {
+        let pairs = Day4::parse(input).unwrap();
+        Coverage::of(pairs.iter().flat_map(|(a, b)| [a, b]))
+    }
+
+    #[test]
+    fn coverage_of_sample_input() {
+        let coverage = coverage(include_str!("sample-input.txt"));
+
+        assert_eq!(
+            coverage.0,
+            [
+                segment(2..=2, 4),
+                segment(3..=3, 5),
+                segment(4..=5, 7),
+                segment(6..=6, 8),
+                segment(7..=7, 6),
+                segment(8..=8, 4),
+                segment(9..=9, 1),
+            ]
+        );
+        assert_eq!(coverage.covered(), 8);
+        assert_eq!(coverage.more_than(5), 4);
+        assert_eq!(coverage.max_depth(), 8);
+        assert!(coverage.gaps().is_empty());
+    }
+
+    #[test]
+    fn gaps_between_assignments() {
+        let coverage = coverage("1-2,5-6\n9-9,2-3\n");
+
+        assert_eq!(coverage.gaps(), [4..=4, 7..=8]);
+        assert_eq!(coverage.covered(), 6);
+        assert_eq!(
+            coverage.to_string(),
+            concat!(
+                "     start         end  elves\n",
+                "         1           1      1  #\n",
+                "         2           2      2  ##\n",
+                "         3           3      1  #\n",
+                "         4           4      0\n",
+                "         5           6      1  #\n",
+                "         7           8      0\n",
+                "         9           9      1  #\n",
+            )
+        );
+    }
+
+    #[test]
+    fn assignments_meeting_end_to_start_merge() {
+        let coverage = coverage("1-3,4-6\n");
+        assert_eq!(coverage.0, [segment(1..=6, 1)]);
+    }
+
+    #[test]
+    fn assignment_up_to_the_last_section() {
+        let coverage = Coverage::of(&[u32::MAX - 1..=u32::MAX, 0..=0]);
+        assert_eq!(coverage.covered(), 3);
+        assert_eq!(coverage.gaps(), [1..=u32::MAX - 2]);
+    }
+
+    #[test]
+    fn no_assignments() {
+        assert_eq!(Coverage::of(&[]), Coverage::default());
+    }
+}
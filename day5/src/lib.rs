@@ -0,0 +1,787 @@
+use nom::branch::alt;
+use nom::bytes::complete::{is_a, tag, take};
+use nom::character::complete::{anychar, digit1, line_ending, multispace0, space0, space1, u8};
+use nom::combinator::eof;
+use nom::multi::{separated_list0, separated_list1};
+use nom::sequence::{delimited, preceded, terminated};
+use nom::{Finish, IResult};
+use std::fmt::Formatter;
+
+use aoc::{Part, Solution};
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+
+    type Input = Input;
+    type Part1 = String;
+    type Part2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input, aoc::Error> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1, aoc::Error> {
+        input.run(&CrateMover9000)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, aoc::Error> {
+        input.run(&CrateMover9001)
+    }
+}
+
+/// How a crane moves crates from the top of one stack to another.
+pub trait Crane {
+    /// Moves `count` crates, or fails leaving both stacks as they were if `from` holds fewer.
+    fn move_crates(
+        &self,
+        from: &mut Vec<SupplyCrate>,
+        to: &mut Vec<SupplyCrate>,
+        count: usize,
+    ) -> Result<(), NotEnoughCrates>;
+}
+
+/// A crane was asked to move more crates than the stack holds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NotEnoughCrates {
+    pub count: usize,
+    pub available: usize,
+}
+
+/// Where the top `count` crates of `stack` start.
+fn top(stack: &[SupplyCrate], count: usize) -> Result<usize, NotEnoughCrates> {
+    stack.len().checked_sub(count).ok_or(NotEnoughCrates {
+        count,
+        available: stack.len(),
+    })
+}
+
+/// Moves crates one by one, which reverses their order on the destination stack.
+pub struct CrateMover9000;
+
+impl Crane for CrateMover9000 {
+    fn move_crates(
+        &self,
+        from: &mut Vec<SupplyCrate>,
+        to: &mut Vec<SupplyCrate>,
+        count: usize,
+    ) -> Result<(), NotEnoughCrates> {
+        let start = top(from, count)?;
+        to.extend(from.drain(start..).rev());
+        Ok(())
+    }
+}
+
+/// Moves all crates at once, keeping their order.
+pub struct CrateMover9001;
+
+impl Crane for CrateMover9001 {
+    fn move_crates(
+        &self,
+        from: &mut Vec<SupplyCrate>,
+        to: &mut Vec<SupplyCrate>,
+        count: usize,
+    ) -> Result<(), NotEnoughCrates> {
+        let start = top(from, count)?;
+        to.extend(from.drain(start..));
+        Ok(())
+    }
+}
+
+/// The crane models, to pick one on the command line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Model {
+    /// Moves one crate at a time (part 1)
+    #[value(name = "9000")]
+    CrateMover9000,
+    /// Moves several crates at once (part 2)
+    #[value(name = "9001")]
+    CrateMover9001,
+}
+
+impl Model {
+    /// The puzzle part that uses this crane.
+    pub fn part(self) -> Part {
+        match self {
+            Self::CrateMover9000 => Part::One,
+            Self::CrateMover9001 => Part::Two,
+        }
+    }
+
+    pub fn crane(self) -> &'static dyn Crane {
+        match self {
+            Self::CrateMover9000 => &CrateMover9000,
+            Self::CrateMover9001 => &CrateMover9001,
+        }
+    }
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SupplyCrate(char);
+
+impl std::fmt::Display for SupplyCrate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[{}]", self.0)
+    }
+}
+
+#[derive(Debug, Clone)]
+struct LoadingDock {
+    stacks: Vec<Vec<SupplyCrate>>,
+}
+
+impl LoadingDock {
+    fn heights(&self) -> Vec<usize> {
+        self.stacks.iter().map(Vec::len).collect()
+    }
+
+    fn apply(&mut self, step: Step, crane: &dyn Crane) -> Result<(), CommandError> {
+        step.check(&self.heights())?;
+
+        let Step { line, command } = step;
+        let [from, to] = self
+            .stacks
+            .get_disjoint_mut([command.from - 1, command.to - 1])
+            .expect("checked that the stacks exist and differ");
+
+        crane.move_crates(from, to, command.count).map_err(
+            |NotEnoughCrates { count, available }| CommandError::InsufficientCrates {
+                line,
+                stack: command.from,
+                count,
+                available,
+            },
+        )
+    }
+
+    /// Checks that every step can be carried out in turn, without moving any crates.
+    fn validate(&self, steps: &[Step]) -> Result<(), CommandError> {
+        let mut heights = self.heights();
+
+        for step in steps {
+            step.check(&heights)?;
+
+            let Command { count, from, to } = step.command;
+            heights[from - 1] -= count;
+            heights[to - 1] += count;
+        }
+
+        Ok(())
+    }
+
+    fn tops(&self) -> Result<String, aoc::Error> {
+        self.stacks
+            .iter()
+            .enumerate()
+            .map(|(idx, stack)| match stack.last() {
+                Some(supply_crate) => Ok(supply_crate.0),
+                None => Err(format!("stack {} is empty", idx + 1).into()),
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Command {
+    count: usize,
+    from: usize,
+    to: usize,
+}
+
+/// A command and the line it is on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Step {
+    line: usize,
+    command: Command,
+}
+
+impl Step {
+    /// Checks that the command can be carried out on stacks of `heights`.
+    fn check(self, heights: &[usize]) -> Result<(), CommandError> {
+        let Step { line, command } = self;
+        let stacks = heights.len();
+
+        for stack in [command.from, command.to] {
+            if stack == 0 || stack > stacks {
+                return Err(CommandError::BadStack {
+                    line,
+                    stack,
+                    stacks,
+                });
+            }
+        }
+
+        if command.from == command.to {
+            return Err(CommandError::SameStack {
+                line,
+                stack: command.from,
+            });
+        }
+
+        let available = heights[command.from - 1];
+        if command.count > available {
+            return Err(CommandError::InsufficientCrates {
+                line,
+                stack: command.from,
+                count: command.count,
+                available,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+/// Why a command cannot be carried out.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CommandError {
+    /// The stack is 0 or past the last of `stacks`.
+    BadStack {
+        line: usize,
+        stack: usize,
+        stacks: usize,
+    },
+    InsufficientCrates {
+        line: usize,
+        stack: usize,
+        count: usize,
+        available: usize,
+    },
+    SameStack {
+        line: usize,
+        stack: usize,
+    },
+}
+
+impl CommandError {
+    pub fn line(&self) -> usize {
+        match *self {
+            Self::BadStack { line, .. }
+            | Self::InsufficientCrates { line, .. }
+            | Self::SameStack { line, .. } => line,
+        }
+    }
+
+    /// What is wrong, without the line.
+    pub fn message(&self) -> String {
+        match *self {
+            Self::BadStack { stack, stacks, .. } => {
+                format!("there is no stack {stack}, only stacks 1 to {stacks}")
+            }
+            Self::InsufficientCrates {
+                stack,
+                count,
+                available,
+                ..
+            } => format!("cannot move {count} crates from stack {stack}, which has {available}"),
+            Self::SameStack { stack, .. } => {
+                format!("cannot move crates from stack {stack} onto itself")
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for CommandError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}: {}", self.line(), self.message())
+    }
+}
+
+impl std::error::Error for CommandError {}
+
+impl From<CommandError> for aoc::Error {
+    fn from(err: CommandError) -> Self {
+        aoc::Error::parse(err.line(), 1, err.message())
+    }
+}
+
+#[derive(Debug)]
+pub struct Input {
+    dock: LoadingDock,
+    commands: Vec<Step>,
+}
+
+impl Input {
+    /// Checks that every command can be carried out, without moving any crates.
+    pub fn validate(&self) -> Result<(), CommandError> {
+        self.dock.validate(&self.commands)
+    }
+
+    /// Rearranges the stacks with `crane`, once all commands are known to be valid, and reads the
+    /// crate on top of each.
+    pub fn run(&self, crane: &dyn Crane) -> Result<String, aoc::Error> {
+        self.validate()?;
+
+        let mut dock = self.dock.clone();
+        for &step in &self.commands {
+            dock.apply(step, crane)?;
+        }
+
+        dock.tops()
+    }
+}
+
+/// Reads the crate rows, the line numbering the stacks below them and then the commands,
+/// reporting the first text that fits none of them.
+fn parse_input(input: &str) -> Result<Input, aoc::Error> {
+    let whole = input;
+    let expected_command = "expected \"move <count> from <stack> to <stack>\"";
+
+    // reading crates stops at anything else, which is only allowed where a line starts
+    let (input, dock) = parse_loading_dock(input)
+        .finish()
+        .map_err(|err| aoc::Error::at(whole, err.input, "expected a crate like [A]"))?;
+    let read = &whole[..whole.len() - input.len()];
+    if !(read.is_empty() || read.ends_with('\n')) {
+        return Err(aoc::Error::at(
+            whole,
+            input.trim_start_matches(' '),
+            "expected a crate like [A], or three spaces for none",
+        ));
+    }
+
+    let (input, _) = parse_stack_numbers(input).finish().map_err(|err| {
+        aoc::Error::at(
+            whole,
+            err.input,
+            "expected the stacks to be numbered, like \" 1   2   3\"",
+        )
+    })?;
+
+    let (input, commands) = preceded(
+        multispace0,
+        separated_list0(is_a("\r\n"), |at| {
+            let (rest, command) = parse_command(at)?;
+            Ok((rest, (at, command)))
+        }),
+    )(input)
+    .finish()
+    .map_err(|err| aoc::Error::at(whole, err.input, expected_command))?;
+
+    let rest = input.trim_start();
+    if !rest.is_empty() {
+        return Err(aoc::Error::at(whole, rest, expected_command));
+    }
+
+    // lines are counted from each command to the next, so the input is only scanned once
+    let mut line = 1;
+    let mut counted = whole;
+    let commands = commands
+        .into_iter()
+        .map(|(at, command)| {
+            let offset = at.as_ptr() as usize - counted.as_ptr() as usize;
+            line += counted[..offset].matches('\n').count();
+            counted = at;
+            Step { line, command }
+        })
+        .collect();
+
+    Ok(Input { dock, commands })
+}
+
+/// The line under the crates numbering the stacks, like " 1   2   3 ".
+fn parse_stack_numbers(input: &str) -> IResult<&str, Vec<&str>> {
+    terminated(
+        delimited(space0, separated_list1(space1, digit1), space0),
+        alt((line_ending, eof)),
+    )(input)
+}
+
+fn parse_command(input: &str) -> IResult<&str, Command> {
+    let (input, _) = tag("move ")(input)?;
+    let (input, count) = u8(input)?;
+    let (input, _) = tag(" from ")(input)?;
+    let (input, from) = u8(input)?;
+    let (input, _) = tag(" to ")(input)?;
+    let (input, to) = u8(input)?;
+
+    Ok((
+        input,
+        Command {
+            count: count as usize,
+            from: from as usize,
+            to: to as usize,
+        },
+    ))
+}
+
+fn parse_loading_dock(input: &str) -> IResult<&str, LoadingDock> {
+    let (input, lines) = separated_list0(is_a("\r\n"), parse_loading_dock_line)(input)?;
+
+    // rows may be ragged, so the widest one decides how many stacks there are
+    let stack_count = lines.iter().map(Vec::len).max().unwrap_or(0);
+    let mut stacks: Vec<Vec<SupplyCrate>> = vec![Vec::new(); stack_count];
+
+    for line in lines.into_iter().rev() {
+        for (stack, supply_crate) in stacks.iter_mut().zip(line) {
+            stack.extend(supply_crate);
+        }
+    }
+
+    Ok((input, LoadingDock { stacks }))
+}
+
+fn parse_loading_dock_line(input: &str) -> IResult<&str, Vec<Option<SupplyCrate>>> {
+    let (input, list) = separated_list0(tag(" "), parse_supply_crate)(input)?;
+    Ok((input, list))
+}
+
+fn parse_supply_crate(input: &str) -> IResult<&str, Option<SupplyCrate>> {
+    let (input, chars) = take(3_usize)(input)?;
+
+    if chars == "   " {
+        Ok((input, None))
+    } else {
+        let (_, char) = delimited(tag("["), anychar, tag("]"))(chars)?;
+        Ok((input, Some(SupplyCrate(char))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nom::Finish;
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+
+    /// Draws `stacks` the way the puzzle does, bottom crates last, followed by `commands`.
+    fn render(stacks: &[Vec<char>], commands: &[Command]) -> String {
+        let height = stacks.iter().map(Vec::len).max().unwrap_or(0);
+        let mut input = String::new();
+
+        for level in (0..height).rev() {
+            let row = stacks
+                .iter()
+                .map(|stack| match stack.get(level) {
+                    Some(c) => format!("[{c}]"),
+                    None => "   ".to_owned(),
+                })
+                .collect::<Vec<_>>();
+            input.push_str(&row.join(" "));
+            input.push('\n');
+        }
+
+        let numbers = (1..=stacks.len())
+            .map(|n| format!(" {n} "))
+            .collect::<Vec<_>>();
+        input.push_str(&numbers.join(" "));
+        input.push_str("\n\n");
+
+        for Command { count, from, to } in commands {
+            input.push_str(&format!("move {count} from {from} to {to}\n"));
+        }
+
+        input
+    }
+
+    fn command() -> impl Strategy<Value = Command> {
+        (0..=255usize, 0..=255usize, 0..=255usize).prop_map(|(count, from, to)| Command {
+            count,
+            from,
+            to,
+        })
+    }
+
+    #[test]
+    fn test_cranes_on_sample_input() {
+        let input = Day5::parse(include_str!("sample-input.txt")).unwrap();
+        assert_eq!(input.run(&CrateMover9000).unwrap(), "CMZ");
+        assert_eq!(input.run(&CrateMover9001).unwrap(), "MCD");
+    }
+
+    /// The error the dry run finds in `commands` on two stacks of two crates, checking that
+    /// running them fails the same way.
+    fn run_error(commands: &str) -> CommandError {
+        let input = format!("[A] [B]\n[C] [D]\n 1   2 \n\n{commands}");
+        let input = Day5::parse(&input).unwrap();
+
+        let err = input.validate().unwrap_err();
+        let expected = format!("line {}, column 1: {}", err.line(), err.message());
+        assert_eq!(
+            input.run(&CrateMover9001).unwrap_err().to_string(),
+            expected
+        );
+        err
+    }
+
+    #[test]
+    fn test_bad_stack() {
+        assert_eq!(
+            run_error("move 1 from 0 to 1\n"),
+            CommandError::BadStack {
+                line: 5,
+                stack: 0,
+                stacks: 2
+            }
+        );
+        assert_eq!(
+            run_error("move 1 from 1 to 2\nmove 1 from 1 to 3\n").to_string(),
+            "line 6: there is no stack 3, only stacks 1 to 2"
+        );
+    }
+
+    #[test]
+    fn test_insufficient_crates() {
+        // the first command leaves stack 1 with one crate, which the dry run must notice
+        assert_eq!(
+            run_error("move 1 from 1 to 2\nmove 2 from 1 to 2\n").to_string(),
+            "line 6: cannot move 2 crates from stack 1, which has 1"
+        );
+    }
+
+    #[test]
+    fn test_same_stack() {
+        assert_eq!(
+            run_error("move 1 from 2 to 2\n"),
+            CommandError::SameStack { line: 5, stack: 2 }
+        );
+    }
+
+    #[test]
+    fn test_cranes_order_moved_crates() {
+        let crates = |s: &str| s.chars().map(SupplyCrate).collect::<Vec<_>>();
+
+        let (mut from, mut to) = (crates("ABC"), crates("X"));
+        CrateMover9000.move_crates(&mut from, &mut to, 2).unwrap();
+        assert_eq!((from, to), (crates("A"), crates("XCB")));
+
+        let (mut from, mut to) = (crates("ABC"), crates("X"));
+        CrateMover9001.move_crates(&mut from, &mut to, 2).unwrap();
+        assert_eq!((from, to), (crates("A"), crates("XBC")));
+    }
+
+    #[test]
+    fn test_cranes_refuse_to_move_missing_crates() {
+        let crates = |s: &str| s.chars().map(SupplyCrate).collect::<Vec<_>>();
+
+        for crane in [Model::CrateMover9000, Model::CrateMover9001].map(Model::crane) {
+            let (mut from, mut to) = (crates("AB"), crates("X"));
+            assert_eq!(
+                crane.move_crates(&mut from, &mut to, 3),
+                Err(NotEnoughCrates {
+                    count: 3,
+                    available: 2
+                })
+            );
+            assert_eq!((from, to), (crates("AB"), crates("X")));
+        }
+    }
+
+    #[test]
+    fn test_parse_supply_crate_empty() {
+        let (_, supply_crate) = parse_supply_crate("   ").finish().unwrap();
+        assert!(supply_crate.is_none());
+    }
+
+    #[test]
+    fn test_parse_supply_crate_complete() {
+        let (remaining, supply_crate) = parse_supply_crate("[A]").finish().unwrap();
+        assert!(remaining.is_empty());
+
+        let supply_crate = supply_crate.unwrap();
+        assert_eq!(supply_crate.0, 'A');
+    }
+
+    #[test]
+    fn test_parse_supply_crate_partial() {
+        let (remaining, supply_crate) = parse_supply_crate("[A]XX").finish().unwrap();
+        assert_eq!(remaining, "XX");
+        assert_eq!(supply_crate, Some(SupplyCrate('A')));
+    }
+
+    #[test]
+    fn test_parse_loading_dock_line_one_crate() {
+        let (remaining, line) = parse_loading_dock_line("[A]\n").finish().unwrap();
+        assert_eq!(remaining, "\n");
+        assert_eq!(line, vec![Some(SupplyCrate('A'))]);
+    }
+
+    #[test]
+    fn test_parse_loading_dock_line_many_crates() {
+        let (remaining, line) = parse_loading_dock_line("[A] [B] [C]").finish().unwrap();
+        assert!(remaining.is_empty());
+        assert_eq!(
+            line,
+            vec![
+                Some(SupplyCrate('A')),
+                Some(SupplyCrate('B')),
+                Some(SupplyCrate('C'))
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_loading_dock_line_with_missing_crates() {
+        let (remaining, line) = parse_loading_dock_line("    [B]    ").finish().unwrap();
+        assert!(remaining.is_empty());
+        assert_eq!(line, vec![None, Some(SupplyCrate('B')), None]);
+    }
+
+    #[test]
+    fn test_parse_loading_dock() {
+        let input = concat!(
+            "    [D]    \n",
+            "[N] [C]    \n",
+            "[Z] [M] [P]\n",
+            " 1   2   3"
+        );
+
+        let (remaining, loading_dock) = parse_loading_dock(input).finish().unwrap();
+        assert_eq!(remaining, " 1   2   3");
+        assert_eq!(
+            loading_dock.stacks,
+            vec![
+                vec![SupplyCrate('Z'), SupplyCrate('N')],
+                vec![SupplyCrate('M'), SupplyCrate('C'), SupplyCrate('D')],
+                vec![SupplyCrate('P')],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_loading_dock_with_ragged_rows() {
+        let (_, loading_dock) = parse_loading_dock("[A]\n[B]     [C]\n").finish().unwrap();
+        assert_eq!(
+            loading_dock.stacks,
+            vec![
+                vec![SupplyCrate('B'), SupplyCrate('A')],
+                vec![],
+                vec![SupplyCrate('C')],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_command() {
+        let (remaining, command) = parse_command("move 1 from 2 to 3\n").finish().unwrap();
+        assert_eq!(remaining, "\n");
+        assert_eq!(
+            command,
+            Command {
+                count: 1,
+                from: 2,
+                to: 3
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_input() {
+        let input = concat!(
+            "    [D]    \n",
+            "[N] [C]    \n",
+            "[Z] [M] [P]\n",
+            " 1   2   3 \n",
+            "move 1 from 2 to 1\n",
+            "move 3 from 1 to 3\n",
+            "move 2 from 2 to 1\n",
+            "move 1 from 1 to 2\n"
+        );
+
+        let input = parse_input(input).unwrap();
+        assert_eq!(input.dock.stacks.len(), 3);
+        let lines = input
+            .commands
+            .iter()
+            .map(|step| step.line)
+            .collect::<Vec<_>>();
+        assert_eq!(lines, [5, 6, 7, 8]);
+    }
+
+    #[test]
+    fn test_parse_input_counts_lines_past_blank_ones() {
+        let input = "[A] [B]\n 1   2 \n\nmove 1 from 1 to 2\n\n\nmove 1 from 2 to 1\r\nmove 1 from 1 to 2\n";
+
+        let input = parse_input(input).unwrap();
+        let lines = input
+            .commands
+            .iter()
+            .map(|step| step.line)
+            .collect::<Vec<_>>();
+        assert_eq!(lines, [4, 7, 8]);
+    }
+
+    #[test]
+    fn test_parse_reports_malformed_command() {
+        let input = concat!(
+            "[A] [B]\n",
+            " 1   2 \n",
+            "move 1 from 1 to 2\n",
+            "move one from 1 to 2\n"
+        );
+
+        let err = Day5::parse(input).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 4, column 1: expected \"move <count> from <stack> to <stack>\""
+        );
+    }
+
+    #[test]
+    fn test_parse_reports_truncated_crate_row() {
+        let err = Day5::parse("[A] [B\n 1   2 \n\nmove 1 from 1 to 2\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 5: expected a crate like [A], or three spaces for none"
+        );
+
+        let err = Day5::parse("    [C]\n[A] XYZ\n 1   2 \n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 5: expected a crate like [A], or three spaces for none"
+        );
+    }
+
+    #[test]
+    fn test_parse_requires_stack_numbers() {
+        let err = Day5::parse("[A] [B]\nmove 1 from 1 to 2\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 1: expected the stacks to be numbered, like \" 1   2   3\""
+        );
+
+        let err = Day5::parse("[A] [B]\n 1   2   x\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 10: expected the stacks to be numbered, like \" 1   2   3\""
+        );
+    }
+
+    #[test]
+    fn test_parse_without_commands() {
+        let input = Day5::parse("[A] [B]\n 1   2 \n").unwrap();
+        assert_eq!(input.run(&CrateMover9000).unwrap(), "AB");
+    }
+
+    proptest! {
+        #[test]
+        fn input_round_trips(
+            stacks in vec(vec(prop::char::range('A', 'Z'), 1..5), 1..9),
+            commands in vec(command(), 1..10),
+        ) {
+            let input = Day5::parse(&render(&stacks, &commands)).unwrap();
+
+            let parsed_stacks = input
+                .dock
+                .stacks
+                .iter()
+                .map(|stack| stack.iter().map(|supply_crate| supply_crate.0).collect())
+                .collect::<Vec<Vec<char>>>();
+            prop_assert_eq!(parsed_stacks, stacks);
+            let parsed_commands = input.commands.iter().map(|step| step.command).collect::<Vec<_>>();
+            prop_assert_eq!(parsed_commands, commands);
+        }
+
+        #[test]
+        fn parse_never_panics(input in concat!(
+            "((\\[[A-C]\\]|   )( (\\[[A-C]\\]|   )){0,3}\n){0,4}",
+            "( 1   2   3 \n\n)?",
+            "(move [0-9] from [0-9] to [0-9]\n){0,3}",
+            "|\\PC*",
+        )) {
+            let _ = Day5::parse(&input);
+        }
+    }
+}
@@ -0,0 +1,95 @@
+use aoc::Solution;
+use itertools::Itertools;
+
+const PACKET_MARKER_SIZE: usize = 4;
+const MESSAGE_MARKER_SIZE: usize = 14;
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+
+    type Input = String;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, aoc::Error> {
+        let datastream = input.trim_end();
+
+        match datastream
+            .char_indices()
+            .find(|(_, c)| !c.is_ascii_lowercase())
+        {
+            Some((idx, c)) => Err(aoc::Error::at(
+                input,
+                &datastream[idx..],
+                format!("expected a lowercase letter, got {c:?}"),
+            )),
+            None => Ok(datastream.to_owned()),
+        }
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1, aoc::Error> {
+        find_start_of_packet(input).ok_or_else(|| "no start-of-packet marker found".into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, aoc::Error> {
+        find_start_of_message(input).ok_or_else(|| "no start-of-message marker found".into())
+    }
+}
+
+fn find_start_of_packet(input: &str) -> Option<usize> {
+    find_marker(input, PACKET_MARKER_SIZE)
+}
+
+fn find_start_of_message(input: &str) -> Option<usize> {
+    find_marker(input, MESSAGE_MARKER_SIZE)
+}
+
+fn find_marker(input: &str, window_size: usize) -> Option<usize> {
+    input
+        .as_bytes()
+        .windows(window_size)
+        .position(|slice| slice.iter().all_unique())
+        .map(|idx| idx + window_size)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{find_start_of_message, find_start_of_packet, Day6};
+    use aoc::Solution;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_find_start_of_packet_with_sample_input() {
+        let idx = find_start_of_packet("mjqjpqmgbljsphdztnvjfqwrcgsmlb");
+        assert_eq!(idx, Some(7));
+    }
+
+    #[test]
+    fn test_find_start_of_message_with_sample_input() {
+        let idx = find_start_of_message("mjqjpqmgbljsphdztnvjfqwrcgsmlb");
+        assert_eq!(idx, Some(19));
+    }
+
+    #[test]
+    fn test_parse_rejects_non_letters() {
+        let err = Day6::parse("mjqj pqm\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 5: expected a lowercase letter, got ' '"
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn parse_accepts_lowercase_datastreams(datastream in "[a-z]{0,64}") {
+            prop_assert_eq!(Day6::parse(&format!("{datastream}\n")).unwrap(), datastream);
+        }
+
+        #[test]
+        fn parse_never_panics(input in "\\PC*") {
+            let _ = Day6::parse(&input);
+        }
+    }
+}
@@ -1,28 +1,8 @@
-use itertools::Itertools;
+use std::process::ExitCode;
 
-const WINDOW_SIZE: usize = 4;
+use aoc::Part;
+use day6::Day6;
 
-fn main() {
-    let input = include_str!("input.txt");
-    let idx = find_start_of_packet(input);
-    println!("start of packet: {idx:?}");
-}
-
-fn find_start_of_packet(input: &str) -> Option<usize> {
-    input
-        .as_bytes()
-        .windows(WINDOW_SIZE)
-        .position(|slice| slice.iter().all_unique())
-        .map(|idx| idx + WINDOW_SIZE)
-}
-
-#[cfg(test)]
-mod tests {
-    use crate::find_start_of_packet;
-
-    #[test]
-    fn test_find_start_of_packet_with_sample_input() {
-        let idx = find_start_of_packet("mjqjpqmgbljsphdztnvjfqwrcgsmlb");
-        assert_eq!(idx, Some(7));
-    }
+fn main() -> ExitCode {
+    aoc::run::<Day6>(&Part::ALL)
 }
@@ -0,0 +1,257 @@
+use std::vec;
+
+use aoc::{ParseError, Solution};
+use camino::Utf8PathBuf;
+use nom::{
+    branch::alt,
+    bytes::complete::{tag, take_while1},
+    combinator::{all_consuming, map},
+    sequence::{preceded, separated_pair},
+    Finish, IResult,
+};
+
+const TOTAL_SPACE: u64 = 70_000_000;
+const NEEDED_FREE_SPACE: u64 = 30_000_000;
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+
+    type Input = FsEntry;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, aoc::Error> {
+        let lines = aoc::parse_lines(input, |line| {
+            all_consuming(parse_line)(line)
+                .finish()
+                .map(|(_, parsed)| parsed)
+                .map_err(|err| {
+                    ParseError::at(line, err.input, "expected a command, a directory or a file")
+                })
+        })?;
+
+        Ok(FsEntry::root().build(&mut lines.into_iter()))
+    }
+
+    fn part1(root: &Self::Input) -> Result<Self::Part1, aoc::Error> {
+        Ok(root
+            .all_dirs()
+            .map(|d| d.total_size())
+            .filter(|&s| s <= 100_000)
+            .sum::<u64>())
+    }
+
+    fn part2(root: &Self::Input) -> Result<Self::Part2, aoc::Error> {
+        let used_space = root.total_size();
+        let free_space = TOTAL_SPACE
+            .checked_sub(used_space)
+            .ok_or_else(|| format!("{used_space} bytes used, more than the disk size"))?;
+        let minimum_space_to_free = NEEDED_FREE_SPACE
+            .checked_sub(free_space)
+            .ok_or_else(|| format!("{free_space} bytes already free, nothing to remove"))?;
+
+        root.children
+            .iter()
+            .filter(|&n| !n.children.is_empty())
+            .map(|n| n.total_size())
+            .filter(|&s| s >= minimum_space_to_free)
+            .min()
+            .ok_or_else(|| format!("no directory frees {minimum_space_to_free} bytes").into())
+    }
+}
+
+fn parse_path(i: &str) -> IResult<&str, Utf8PathBuf> {
+    map(
+        take_while1(|c: char| "abcdefghijklmnopqrstuvwxyz./".contains(c)),
+        Into::into,
+    )(i)
+}
+
+#[derive(Debug)]
+struct Ls;
+
+fn parse_ls(i: &str) -> IResult<&str, Ls> {
+    map(tag("ls"), |_| Ls)(i)
+}
+
+#[derive(Debug)]
+struct Cd(Utf8PathBuf);
+
+fn parse_cd(i: &str) -> IResult<&str, Cd> {
+    map(preceded(tag("cd "), parse_path), Cd)(i)
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Command {
+    Ls,
+    Cd(Utf8PathBuf),
+}
+
+impl From<Ls> for Command {
+    fn from(_: Ls) -> Self {
+        Command::Ls
+    }
+}
+
+impl From<Cd> for Command {
+    fn from(cd: Cd) -> Self {
+        Command::Cd(cd.0)
+    }
+}
+
+fn parse_command(i: &str) -> IResult<&str, Command> {
+    let (i, _) = tag("$ ")(i)?;
+    alt((map(parse_ls, Into::into), map(parse_cd, Into::into)))(i)
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Entry {
+    #[allow(dead_code)]
+    Dir(Utf8PathBuf),
+    File(u64, Utf8PathBuf),
+}
+
+fn parse_entry(i: &str) -> IResult<&str, Entry> {
+    let parse_file = map(
+        separated_pair(nom::character::complete::u64, tag(" "), parse_path),
+        |(size, path)| Entry::File(size, path),
+    );
+
+    let parse_dir = map(preceded(tag("dir "), parse_path), Entry::Dir);
+
+    alt((parse_file, parse_dir))(i)
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Line {
+    Command(Command),
+    Entry(Entry),
+}
+
+fn parse_line(i: &str) -> IResult<&str, Line> {
+    alt((
+        map(parse_command, Line::Command),
+        map(parse_entry, Line::Entry),
+    ))(i)
+}
+
+#[derive(Debug)]
+pub struct FsEntry {
+    size: u64,
+    #[allow(dead_code)]
+    path: Utf8PathBuf,
+    children: Vec<FsEntry>,
+}
+
+impl FsEntry {
+    fn root() -> Self {
+        Self {
+            size: 0,
+            path: "/".into(),
+            children: vec![],
+        }
+    }
+
+    fn new(path: &Utf8PathBuf) -> Self {
+        Self::new_with_size(0, path)
+    }
+
+    fn new_with_size(size: u64, path: &Utf8PathBuf) -> Self {
+        Self {
+            size,
+            path: path.clone(),
+            children: vec![],
+        }
+    }
+
+    fn total_size(&self) -> u64 {
+        self.size + self.children.iter().map(|c| c.total_size()).sum::<u64>()
+    }
+
+    fn all_dirs(&self) -> Box<dyn Iterator<Item = &FsEntry> + '_> {
+        Box::new(
+            std::iter::once(self).chain(
+                self.children
+                    .iter()
+                    .filter(|c| !c.children.is_empty())
+                    .flat_map(|c| c.all_dirs()),
+            ),
+        )
+    }
+
+    fn build(mut self, it: &mut dyn Iterator<Item = Line>) -> Self {
+        while let Some(line) = it.next() {
+            match line {
+                Line::Command(Command::Cd(path)) => match path.as_str() {
+                    "/" => {
+                        // ignore
+                    }
+                    ".." => break,
+                    _ => {
+                        let node = FsEntry::new(&path);
+                        self.children.push(node.build(it));
+                    }
+                },
+                Line::Entry(Entry::File(size, path)) => {
+                    let node = FsEntry::new_with_size(size, &path);
+                    self.children.push(node);
+                }
+                _ => {
+                    // ignore
+                }
+            }
+        }
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_sample_input() {
+        let root = Day7::parse(include_str!("sample-input.txt")).unwrap();
+        assert_eq!(Day7::part1(&root).unwrap(), 95437);
+        assert_eq!(Day7::part2(&root).unwrap(), 24933642);
+    }
+
+    fn line() -> impl Strategy<Value = (String, Line)> {
+        let name = "[a-z.]{1,8}";
+
+        prop_oneof![
+            Just("$ ls").prop_map(|text| (text.to_owned(), Line::Command(Command::Ls))),
+            name.prop_map(|name| (
+                format!("$ cd {name}"),
+                Line::Command(Command::Cd(name.into()))
+            )),
+            name.prop_map(|name| (format!("dir {name}"), Line::Entry(Entry::Dir(name.into())))),
+            (any::<u64>(), name).prop_map(|(size, name)| {
+                (
+                    format!("{size} {name}"),
+                    Line::Entry(Entry::File(size, name.into())),
+                )
+            }),
+        ]
+    }
+
+    proptest! {
+        #[test]
+        fn line_round_trips((text, expected) in line()) {
+            let (rest, parsed) = parse_line(&text).unwrap();
+            prop_assert_eq!(rest, "");
+            prop_assert_eq!(parsed, expected);
+        }
+
+        #[test]
+        fn parse_never_panics(
+            input in "((\\$ (ls|cd [a-z./]{1,3})|dir [a-z]|[0-9]{1,3} [a-z])\n){0,8}|\\PC*",
+        ) {
+            let _ = Day7::parse(&input);
+        }
+    }
+}
@@ -0,0 +1,270 @@
+use aoc::{ParseError, Solution};
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+
+    type Input = Forest;
+    type Part1 = usize;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, aoc::Error> {
+        Forest::parse(input)
+    }
+
+    fn part1(forest: &Self::Input) -> Result<Self::Part1, aoc::Error> {
+        Ok(forest.visible_tree_count())
+    }
+
+    fn part2(forest: &Self::Input) -> Result<Self::Part2, aoc::Error> {
+        forest
+            .best_scenic_score()
+            .ok_or_else(|| "the forest has no trees".into())
+    }
+}
+
+struct Tree {
+    x: usize,
+    y: usize,
+    height: u8,
+}
+
+pub struct Forest {
+    trees: Vec<Vec<u8>>,
+}
+
+impl Forest {
+    fn parse(input: &str) -> Result<Self, aoc::Error> {
+        let mut width = None;
+
+        let trees = aoc::parse_lines(input, |line| {
+            let row = line
+                .bytes()
+                .enumerate()
+                .map(|(idx, byte)| match byte {
+                    b'0'..=b'9' => Ok(byte - b'0'),
+                    _ => Err(ParseError::new(format!(
+                        "expected a tree height, got {:?}",
+                        byte as char
+                    ))
+                    .at_column(idx + 1)),
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            match width {
+                Some(width) if width != row.len() => Err(ParseError::new(format!(
+                    "expected {width} trees, got {}",
+                    row.len()
+                ))),
+                _ => {
+                    width = Some(row.len());
+                    Ok(row)
+                }
+            }
+        })?;
+
+        Ok(Self { trees })
+    }
+
+    fn visible_tree_count(&self) -> usize {
+        self.trees().filter(|tree| self.is_visible(tree)).count()
+    }
+
+    fn best_scenic_score(&self) -> Option<u32> {
+        self.trees().map(|tree| self.scenic_score(&tree)).max()
+    }
+
+    fn trees(&self) -> impl Iterator<Item = Tree> + '_ {
+        self.trees.iter().enumerate().flat_map(move |(y, row)| {
+            row.iter().enumerate().map(move |(x, height)| Tree {
+                x,
+                y,
+                height: *height,
+            })
+        })
+    }
+
+    fn is_visible(&self, tree: &Tree) -> bool {
+        self.is_visible_from_left(tree)
+            || self.is_visible_from_right(tree)
+            || self.is_visible_from_top(tree)
+            || self.is_visible_from_bottom(tree)
+    }
+
+    fn is_visible_from_top(&self, tree: &Tree) -> bool {
+        for iy in 0..tree.y {
+            let height = self.trees.get(iy).unwrap().get(tree.x).unwrap();
+            if *height >= tree.height {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    fn is_visible_from_bottom(&self, tree: &Tree) -> bool {
+        for iy in (tree.y + 1)..(self.trees.len()) {
+            let height = self.trees.get(iy).unwrap().get(tree.x).unwrap();
+            if *height >= tree.height {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    fn is_visible_from_left(&self, tree: &Tree) -> bool {
+        let row = self.trees.get(tree.y).unwrap();
+
+        for ix in 0..tree.x {
+            let height = row.get(ix).unwrap();
+            if *height >= tree.height {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    fn is_visible_from_right(&self, tree: &Tree) -> bool {
+        let row = self.trees.get(tree.y).unwrap();
+
+        for ix in (tree.x + 1)..row.len() {
+            let height = row.get(ix).unwrap();
+            if *height >= tree.height {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    fn scenic_score(&self, tree: &Tree) -> u32 {
+        self.scenic_score_top(tree)
+            * self.scenic_score_left(tree)
+            * self.scenic_score_right(tree)
+            * self.scenic_score_bottom(tree)
+    }
+
+    fn scenic_score_top(&self, tree: &Tree) -> u32 {
+        let mut score = 0;
+
+        for iy in (0..tree.y).rev() {
+            score += 1;
+            if *self.trees.get(iy).unwrap().get(tree.x).unwrap() >= tree.height {
+                break;
+            }
+        }
+
+        score
+    }
+
+    fn scenic_score_bottom(&self, tree: &Tree) -> u32 {
+        let mut score = 0;
+
+        for iy in (tree.y + 1)..(self.trees.len()) {
+            score += 1;
+            if *self.trees.get(iy).unwrap().get(tree.x).unwrap() >= tree.height {
+                break;
+            }
+        }
+
+        score
+    }
+
+    fn scenic_score_left(&self, tree: &Tree) -> u32 {
+        let mut score = 0;
+        let row = self.trees.get(tree.y).unwrap();
+
+        for ix in (0..tree.x).rev() {
+            score += 1;
+            if *row.get(ix).unwrap() >= tree.height {
+                break;
+            }
+        }
+
+        score
+    }
+
+    fn scenic_score_right(&self, tree: &Tree) -> u32 {
+        let mut score = 0;
+        let row = self.trees.get(tree.y).unwrap();
+
+        for ix in (tree.x + 1)..row.len() {
+            score += 1;
+            if *row.get(ix).unwrap() >= tree.height {
+                break;
+            }
+        }
+
+        score
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn scenic_score_first_example() {
+        let forest = Forest::parse(include_str!("sample-input.txt")).unwrap();
+
+        let tree = Tree {
+            y: 1,
+            x: 2,
+            height: 5,
+        };
+
+        assert_eq!(forest.scenic_score_top(&tree), 1);
+        assert_eq!(forest.scenic_score_left(&tree), 1);
+        assert_eq!(forest.scenic_score_right(&tree), 2);
+        assert_eq!(forest.scenic_score_bottom(&tree), 2);
+        assert_eq!(forest.scenic_score(&tree), 4);
+    }
+
+    #[test]
+    fn visible_tree_count() {
+        let forest = Forest::parse(include_str!("sample-input.txt")).unwrap();
+        assert_eq!(forest.visible_tree_count(), 21);
+    }
+
+    #[test]
+    fn best_scenic_score() {
+        let forest = Forest::parse(include_str!("sample-input.txt")).unwrap();
+        assert_eq!(forest.best_scenic_score(), Some(8));
+    }
+
+    #[test]
+    fn parse_rejects_non_digits() {
+        let err = Forest::parse("303\n2a5\n").err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 2: expected a tree height, got 'a'"
+        );
+    }
+
+    #[test]
+    fn parse_rejects_ragged_rows() {
+        let err = Forest::parse("303\n25\n").err().unwrap();
+        assert_eq!(err.to_string(), "line 2, column 1: expected 3 trees, got 2");
+    }
+
+    proptest! {
+        #[test]
+        fn parse_keeps_every_height(rows in prop::collection::vec("[0-9]{5}", 1..5)) {
+            let forest = Forest::parse(&rows.join("\n")).unwrap();
+            let heights = rows
+                .iter()
+                .map(|row| row.bytes().map(|b| b - b'0').collect::<Vec<_>>())
+                .collect::<Vec<_>>();
+            prop_assert_eq!(forest.trees, heights);
+        }
+
+        #[test]
+        fn parse_never_panics(input in "([0-9]{0,4}\n){0,4}|\\PC*") {
+            let _ = Forest::parse(&input);
+        }
+    }
+}
@@ -0,0 +1,245 @@
+use aoc::{ParseError, Solution};
+use nom::bytes::complete::tag;
+use nom::character::complete::{anychar, u8};
+use nom::combinator::{all_consuming, map_opt};
+use nom::sequence::separated_pair;
+use nom::{Finish, IResult};
+use std::collections::HashSet;
+use std::fmt;
+use std::fmt::Formatter;
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+
+    type Input = Vec<Move>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, aoc::Error> {
+        parse_input(input)
+    }
+
+    fn part1(moves: &Self::Input) -> Result<Self::Part1, aoc::Error> {
+        Ok(count_visited(2, moves))
+    }
+
+    fn part2(moves: &Self::Input) -> Result<Self::Part2, aoc::Error> {
+        Ok(count_visited(10, moves))
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Default)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+impl Point {
+    fn move_to(self, direction: Direction) -> Self {
+        match direction {
+            Direction::Up => Self {
+                x: self.x,
+                y: self.y + 1,
+            },
+            Direction::Right => Self {
+                x: self.x + 1,
+                y: self.y,
+            },
+            Direction::Down => Self {
+                x: self.x,
+                y: self.y - 1,
+            },
+            Direction::Left => Self {
+                x: self.x - 1,
+                y: self.y,
+            },
+        }
+    }
+}
+
+impl fmt::Debug for Point {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+struct Rope {
+    knots: Vec<Point>,
+    visited: HashSet<Point>,
+}
+
+impl Rope {
+    fn new(knots: usize) -> Self {
+        let zero = Point::default();
+        let knots = vec![zero; knots];
+
+        let mut visited = HashSet::new();
+        visited.insert(zero);
+
+        Self { knots, visited }
+    }
+
+    fn pull(&mut self, direction: Direction) {
+        let knots = &mut self.knots;
+
+        // move the head
+        let mut previous = {
+            let head = knots.get_mut(0).unwrap();
+            *head = head.move_to(direction);
+            *head
+        };
+
+        for knot in knots.iter_mut().skip(1) {
+            match (previous.x - knot.x, previous.y - knot.y) {
+                // straight up
+                (0, 2) => {
+                    *knot = knot.move_to(Direction::Up);
+                }
+                //straight right
+                (2, 0) => {
+                    *knot = knot.move_to(Direction::Right);
+                }
+                // straight down
+                (0, -2) => {
+                    *knot = knot.move_to(Direction::Down);
+                }
+                // straight left
+                (-2, 0) => {
+                    *knot = knot.move_to(Direction::Left);
+                }
+                // up and right
+                (1, 2) | (2, 1) | (2, 2) => {
+                    *knot = knot.move_to(Direction::Up).move_to(Direction::Right);
+                }
+                // down and right
+                (1, -2) | (2, -1) | (2, -2) => {
+                    *knot = knot.move_to(Direction::Down).move_to(Direction::Right);
+                }
+                // down and left
+                (-2, -1) | (-1, -2) | (-2, -2) => {
+                    *knot = knot.move_to(Direction::Down).move_to(Direction::Left);
+                }
+                // up and left
+                (-2, 1) | (-1, 2) | (-2, 2) => {
+                    *knot = knot.move_to(Direction::Up).move_to(Direction::Left);
+                }
+                _ => {
+                    break;
+                }
+            }
+
+            previous = *knot;
+        }
+
+        // record new tail position
+        self.visited.insert(*knots.last().unwrap());
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Move {
+    direction: Direction,
+    steps: u8,
+}
+
+fn parse_direction(input: &str) -> IResult<&str, Direction> {
+    map_opt(anychar, |c| match c {
+        'U' => Some(Direction::Up),
+        'R' => Some(Direction::Right),
+        'D' => Some(Direction::Down),
+        'L' => Some(Direction::Left),
+        _ => None,
+    })(input)
+}
+
+fn parse_move(input: &str) -> IResult<&str, Move> {
+    let (input, (direction, steps)) = separated_pair(parse_direction, tag(" "), u8)(input)?;
+    Ok((input, Move { direction, steps }))
+}
+
+fn parse_input(input: &str) -> Result<Vec<Move>, aoc::Error> {
+    aoc::parse_lines(input, |line| {
+        all_consuming(parse_move)(line)
+            .finish()
+            .map(|(_, parsed)| parsed)
+            .map_err(|err| ParseError::at(line, err.input, "expected <U|R|D|L>SP<steps>"))
+    })
+}
+
+fn count_visited(knots: usize, moves: &[Move]) -> usize {
+    let mut rope = Rope::new(knots);
+
+    for &Move { direction, steps } in moves {
+        for _ in 0..steps {
+            rope.pull(direction)
+        }
+    }
+
+    rope.visited.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_sample_input_part_1_with_two_knots() {
+        let visited = count_visited(2, &parse_input(include_str!("sample-input-1.txt")).unwrap());
+        assert_eq!(visited, visited);
+    }
+
+    #[test]
+    fn test_sample_input_part_1_with_ten_knots() {
+        let visited = count_visited(
+            10,
+            &parse_input(include_str!("sample-input-1.txt")).unwrap(),
+        );
+        assert_eq!(visited, visited);
+    }
+
+    #[test]
+    fn test_sample_input_part_2() {
+        let visited = count_visited(
+            10,
+            &parse_input(include_str!("sample-input-2.txt")).unwrap(),
+        );
+        assert_eq!(36, visited);
+    }
+
+    #[test]
+    fn test_parse_input_reports_malformed_move() {
+        let err = parse_input("R 4\nU four\n").err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 3: expected <U|R|D|L>SP<steps>"
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn move_round_trips(direction in 0..4usize, steps: u8) {
+            let directions = [Direction::Up, Direction::Right, Direction::Down, Direction::Left];
+            let line = format!("{} {steps}", b"URDL"[direction] as char);
+
+            let (rest, parsed) = parse_move(&line).unwrap();
+            prop_assert_eq!(rest, "");
+            prop_assert_eq!(parsed, Move { direction: directions[direction], steps });
+        }
+
+        #[test]
+        fn parse_never_panics(input in "([URDLX] [0-9]{1,4}\n){0,5}|\\PC*") {
+            let _ = parse_input(&input);
+        }
+    }
+}